@@ -0,0 +1,167 @@
+use utils::{
+    error::parse_at,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 3;
+
+    type Input = Vec<[i32; 3]>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = stream::parse_lines(input, parse_row)?;
+        check_rows(rows.len(), input, input.lines().last().unwrap_or(input))?;
+        Ok(rows)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_triangles(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_triangles_transposed(input).into()
+    }
+}
+
+fn good_triangle(tri: &mut [i32; 3]) -> bool {
+    tri.sort();
+    tri[0] + tri[1] > tri[2]
+}
+
+pub fn parse_row(line: &str) -> Result<[i32; 3], ParseError> {
+    let line_strs = line.split_whitespace().collect::<Vec<&str>>();
+    if line_strs.len() < 3 {
+        return Err(ParseError::at_end(line, line, "three lengths"));
+    }
+    if let Some(extra) = line_strs.get(3) {
+        return Err(ParseError::at(line, extra, "the end of the line"));
+    }
+
+    let mut tri: [i32; 3] = [0; 3];
+    for (side, length) in tri.iter_mut().zip(line_strs) {
+        *side = parse_at(line, length, "a length")?;
+    }
+
+    Ok(tri)
+}
+
+/// Part 2 reads the triangles down the columns of each group of three rows, so there have
+/// to be a whole number of groups. Errors point at the end of `last`, a slice of `input`.
+fn check_rows(rows: usize, input: &str, last: &str) -> Result<(), ParseError> {
+    if rows.is_multiple_of(3) {
+        Ok(())
+    } else {
+        Err(ParseError::at_end(
+            input,
+            last,
+            format!("{} more rows to make whole groups of three", 3 - rows % 3),
+        ))
+    }
+}
+
+pub fn count_triangles(rows: &[[i32; 3]]) -> u32 {
+    let mut count = 0;
+
+    for row in rows {
+        let mut tri = *row;
+
+        //Add to count
+        if good_triangle(&mut tri) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// The triangles read down the columns of each group of three rows, of which there must be a
+/// whole number.
+pub fn count_triangles_transposed(rows: &[[i32; 3]]) -> u32 {
+    let mut count = 0;
+    let mut buffer = [[0i32; 3]; 3];
+
+    for group in rows.chunks(3) {
+        for (index, row) in group.iter().enumerate() {
+            for i in 0..3 {
+                buffer[i][index] = row[i];
+            }
+        }
+
+        for b in &mut buffer {
+            if good_triangle(b) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// The triangles counted so far, and the rows of the current group of three.
+#[derive(Default)]
+pub struct Counts {
+    rows: usize,
+    count: u32,
+    transposed: u32,
+    group: [[i32; 3]; 3],
+}
+
+impl Stream for Day3 {
+    type State = Counts;
+
+    fn read_line(counts: &mut Counts, line: &str) -> Result<(), ParseError> {
+        let row = parse_row(line)?;
+        counts.count += count_triangles(&[row]);
+
+        counts.group[counts.rows % 3] = row;
+        counts.rows += 1;
+        if counts.rows.is_multiple_of(3) {
+            counts.transposed += count_triangles_transposed(&counts.group);
+        }
+        Ok(())
+    }
+
+    fn finish(counts: &Counts, last: &str) -> Result<(), ParseError> {
+        check_rows(counts.rows, last, last)
+    }
+
+    fn answer1(counts: &Counts) -> Answer {
+        counts.count.into()
+    }
+
+    fn answer2(counts: &Counts) -> Answer {
+        counts.transposed.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day3>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day3>();
+    }
+
+    #[test]
+    fn rejects_rows_not_in_threes() {
+        use utils::{stream, Solution};
+
+        let input = "1 2 3\n4 5 6\n7 8 9\n10 11 12\n";
+        let err = super::Day3::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 9));
+        assert_eq!(err.expected, "2 more rows to make whole groups of three");
+
+        let Err(streamed) = stream::read::<super::Day3>(input.as_bytes()) else {
+            panic!("four rows should not stream");
+        };
+        assert_eq!(streamed.to_string(), err.in_puzzle(2016, 3).to_string());
+    }
+}
@@ -0,0 +1,186 @@
+use std::cmp::Reverse;
+
+use regex::Regex;
+use utils::{
+    error::parse_at,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Room>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let room_pattern = room_pattern();
+        stream::parse_lines(input, |line| parse_room(&room_pattern, line))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        sum_real_rooms(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        north_pole_answer(north_pole_sector(input))
+    }
+}
+
+pub struct Room {
+    name: String,
+    sector_id: u32,
+    checksum: String,
+}
+
+impl Room {
+    fn is_real(&self) -> bool {
+        name_checksum(&self.name) == self.checksum
+    }
+}
+
+pub fn room_pattern() -> Regex {
+    Regex::new(r"^([a-z-]+)-(\d+)\[([a-z]{5})\]$").unwrap()
+}
+
+pub fn parse_room(room_pattern: &Regex, line: &str) -> Result<Room, ParseError> {
+    let parts = room_pattern.captures(line).ok_or_else(|| {
+        ParseError::at(
+            line,
+            line,
+            "a room like `name-123[check]`, in lowercase letters",
+        )
+    })?;
+
+    Ok(Room {
+        name: parts[1].to_string(),
+        sector_id: parse_at(line, &parts[2], "a sector ID")?,
+        checksum: parts[3].to_string(),
+    })
+}
+
+pub fn sum_real_rooms(rooms: &[Room]) -> u32 {
+    rooms
+        .iter()
+        .filter(|room| room.is_real())
+        .map(|room| room.sector_id)
+        .sum()
+}
+
+pub fn north_pole_sector(rooms: &[Room]) -> Option<u32> {
+    rooms
+        .iter()
+        .filter(|room| room.is_real())
+        .find(|room| name_decrypt(&room.name, room.sector_id).contains("northpole"))
+        .map(|room| room.sector_id)
+}
+
+/// The five most common letters in `room_name`, ties broken alphabetically. Names with
+/// fewer than five different letters give fewer, so no checksum matches them.
+pub fn name_checksum(room_name: &str) -> String {
+    let mut counts = [0u32; 26];
+    for c in room_name.bytes().filter(u8::is_ascii_lowercase) {
+        counts[(c - b'a') as usize] += 1;
+    }
+
+    let mut letters = (b'a'..=b'z')
+        .filter(|&c| counts[(c - b'a') as usize] > 0)
+        .collect::<Vec<_>>();
+    // The sort is stable, so letters with the same count stay in alphabetical order.
+    letters.sort_by_key(|&c| Reverse(counts[(c - b'a') as usize]));
+
+    letters.into_iter().take(5).map(char::from).collect()
+}
+
+/// The answer to part 2, or why there is none: not every input, such as the example, has a
+/// room storing north pole objects.
+fn north_pole_answer(sector: Option<u32>) -> Answer {
+    match sector {
+        Some(sector) => sector.into(),
+        None => Answer::failed("no room stores north pole objects"),
+    }
+}
+
+pub fn name_decrypt(room_name: &str, sector_id: u32) -> String {
+    let mut result = Vec::<char>::new();
+
+    for c in room_name.chars() {
+        if c == '-' {
+            result.push(' ');
+        } else {
+            let shift = c as u32 - b'a' as u32 + sector_id % 26;
+            result.push(((shift % 26) as u8 + b'a') as char)
+        }
+    }
+
+    result.iter().cloned().collect::<String>()
+}
+
+/// The sector IDs of the real rooms seen so far, and of the first to store north pole
+/// objects.
+pub struct Rooms {
+    room_pattern: Regex,
+    sector_sum: u32,
+    north_pole: Option<u32>,
+}
+
+impl Default for Rooms {
+    fn default() -> Self {
+        Rooms {
+            room_pattern: room_pattern(),
+            sector_sum: 0,
+            north_pole: None,
+        }
+    }
+}
+
+impl Stream for Day4 {
+    type State = Rooms;
+
+    fn read_line(rooms: &mut Rooms, line: &str) -> Result<(), ParseError> {
+        let room = [parse_room(&rooms.room_pattern, line)?];
+        rooms.sector_sum += sum_real_rooms(&room);
+        rooms.north_pole = rooms.north_pole.or_else(|| north_pole_sector(&room));
+        Ok(())
+    }
+
+    fn answer1(rooms: &Rooms) -> Answer {
+        rooms.sector_sum.into()
+    }
+
+    fn answer2(rooms: &Rooms) -> Answer {
+        north_pole_answer(rooms.north_pole)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day4>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day4>();
+    }
+
+    #[test]
+    fn rejects_rooms_that_are_not_lowercase() {
+        use utils::Solution;
+
+        for room in ["Aaaaa-bbb-123[abcde]", "a1-bbb-123[abcde]", "abc-123[abc]"] {
+            let Err(err) = super::Day4::parse(room) else {
+                panic!("{room} should not parse");
+            };
+            assert_eq!((err.line, err.column), (1, 1), "{room}");
+        }
+        assert_eq!(super::name_checksum("a-bb-a"), "ab");
+        assert_eq!(
+            super::Day4::part2(&super::Day4::parse("abcde-1[abcde]").unwrap()).failure(),
+            Some("no room stores north pole objects")
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use utils::{error::parse_at, stream::Stream, Answer, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    /// Total calories carried by each elf.
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split("\n\n")
+            .map(|elf| {
+                elf.lines()
+                    .map(|item| calories(item).map_err(|err| err.within(input, item)))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        question_a(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        question_b(input).into()
+    }
+}
+
+pub fn calories(item: &str) -> Result<u32, ParseError> {
+    parse_at(item, item.trim(), "a calorie count")
+}
+
+/// The most calories carried by one elf. There is always at least one: `parse` splits even
+/// an empty input into one empty group, and streaming passes all four of its totals.
+pub fn question_a(elves: &[u32]) -> u32 {
+    *elves.iter().max().unwrap()
+}
+
+pub fn question_b(elves: &[u32]) -> u32 {
+    let mut top = [0; 3];
+
+    for &calories in elves {
+        let top_min = top.iter_mut().min().unwrap();
+        if calories > *top_min {
+            *top_min = calories;
+        }
+    }
+
+    top.iter().sum::<u32>()
+}
+
+/// The three elves carrying the most so far, and what the current elf carries.
+#[derive(Default)]
+pub struct Elves {
+    top: [u32; 3],
+    current: u32,
+}
+
+impl Elves {
+    fn totals(&self) -> [u32; 4] {
+        [self.top[0], self.top[1], self.top[2], self.current]
+    }
+}
+
+impl Stream for Day1 {
+    type State = Elves;
+
+    fn read_line(elves: &mut Elves, line: &str) -> Result<(), ParseError> {
+        if line.is_empty() {
+            let top_min = elves.top.iter_mut().min().unwrap();
+            *top_min = (*top_min).max(elves.current);
+            elves.current = 0;
+        } else {
+            elves.current += calories(line)?;
+        }
+        Ok(())
+    }
+
+    fn answer1(elves: &Elves) -> Answer {
+        question_a(&elves.totals()).into()
+    }
+
+    fn answer2(elves: &Elves) -> Answer {
+        question_b(&elves.totals()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day1>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day1>();
+    }
+
+    #[test]
+    fn answers_empty_inputs() {
+        use utils::Solution;
+
+        let elves = super::Day1::parse("").unwrap();
+        assert_eq!(super::Day1::part1(&elves), 0u32.into());
+    }
+}
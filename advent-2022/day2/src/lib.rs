@@ -0,0 +1,93 @@
+use utils::{
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    /// The two columns of the strategy guide, as raw bytes.
+    type Input = Vec<(u8, u8)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_round)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        question_a(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        question_b(input).into()
+    }
+}
+
+pub fn parse_round(line: &str) -> Result<(u8, u8), ParseError> {
+    match line.as_bytes() {
+        [left @ b'A'..=b'C', b' ', right @ b'X'..=b'Z'] => Ok((*left, *right)),
+        _ => Err(ParseError::at(line, line, "a round like `A X`")),
+    }
+}
+
+pub fn question_a(rounds: &[(u8, u8)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, me)| score_round1(opponent, me) as u32)
+        .sum()
+}
+
+fn score_round1(opponent: u8, me: u8) -> u8 {
+    (me - b'W') + 3 * ((me - opponent + 2) % 3)
+}
+
+pub fn question_b(rounds: &[(u8, u8)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, outcome)| score_round2(opponent, outcome) as u32)
+        .sum()
+}
+
+fn score_round2(opponent: u8, outcome: u8) -> u8 {
+    let shift = outcome - b'X' + 2; // shift 2 on to lose, 3 (=0 cyclically) to draw, 4=1 to win
+    let me = b'X' + (opponent - b'A' + shift) % 3; // calculate shift relative to opponent
+    (me - b'W') + 3 * (outcome - b'X')
+}
+
+/// The total score so far, reading the second column as a shape and as an outcome.
+#[derive(Default)]
+pub struct Scores(u32, u32);
+
+impl Stream for Day2 {
+    type State = Scores;
+
+    fn read_line(scores: &mut Scores, line: &str) -> Result<(), ParseError> {
+        let round = [parse_round(line)?];
+        scores.0 += question_a(&round);
+        scores.1 += question_b(&round);
+        Ok(())
+    }
+
+    fn answer1(scores: &Scores) -> Answer {
+        scores.0.into()
+    }
+
+    fn answer2(scores: &Scores) -> Answer {
+        scores.1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day2>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day2>();
+    }
+}
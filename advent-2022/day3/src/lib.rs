@@ -0,0 +1,246 @@
+use utils::{
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    /// One rucksack's contents per line.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_sack)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        answer(question_a(input))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        answer(question_b(input))
+    }
+}
+
+/// Reads a sack, which must hold an even number of items, one to each letter.
+pub fn parse_sack(line: &str) -> Result<String, ParseError> {
+    if let Some((at, item)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::at(
+            line,
+            &line[at..at + item.len_utf8()],
+            "an item from `a` to `z` or `A` to `Z`",
+        ));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::at_end(
+            line,
+            line,
+            "another item to fill both halves",
+        ));
+    }
+    Ok(line.to_string())
+}
+
+fn shared_item(sack: &str) -> Option<char> {
+    let (left, right) = sack.split_at(sack.len() / 2);
+    left.chars().find(|c| right.contains(*c))
+}
+
+fn badge(group: &[String]) -> Option<char> {
+    match group {
+        [left, mid, right] => left
+            .chars()
+            .find(|c| mid.contains(*c) && right.contains(*c)),
+        _ => None,
+    }
+}
+
+/// Why part 1 has no answer, when sack number `sack`, counting from 1, is the first with
+/// nothing in both halves.
+fn no_shared_item(sack: usize) -> String {
+    format!("sack {sack} has no item in both halves")
+}
+
+/// Why part 2 has no answer, when the group starting at sack number `first` is the first
+/// with no badge.
+fn no_badge(first: usize) -> String {
+    format!("the group starting at sack {first} has no badge")
+}
+
+/// Why part 2 has no answer, when the last group is only `sacks` sacks.
+fn short_group(sacks: usize) -> String {
+    format!("the last group has only {sacks} of its three sacks")
+}
+
+/// The sum of the priorities of the item in both halves of each sack, or why there is
+/// none.
+pub fn question_a(sacks: &[String]) -> Result<u32, String> {
+    sacks
+        .iter()
+        .enumerate()
+        .map(|(i, sack)| {
+            shared_item(sack)
+                .map(val)
+                .ok_or_else(|| no_shared_item(i + 1))
+        })
+        .sum()
+}
+
+fn val(c: char) -> u32 {
+    let mut ret = u32::from(c);
+
+    ret -= if ret >= 97 { 97 - 1 } else { 65 - 27 };
+    ret
+}
+
+/// The sum of the priorities of the badge of each group of three sacks, or why there is
+/// none.
+pub fn question_b(sacks: &[String]) -> Result<u32, String> {
+    sacks
+        .chunks(3)
+        .enumerate()
+        .map(|(i, group)| match group.len() {
+            3 => badge(group).map(val).ok_or_else(|| no_badge(3 * i + 1)),
+            sacks => Err(short_group(sacks)),
+        })
+        .sum()
+}
+
+fn answer(priorities: Result<u32, String>) -> Answer {
+    match priorities {
+        Ok(priorities) => priorities.into(),
+        Err(why) => Answer::failed(why),
+    }
+}
+
+/// The priorities summed so far, why either part has no answer once that is known, and
+/// the sacks of the group not yet complete.
+#[derive(Default)]
+pub struct Priorities {
+    sacks: usize,
+    items: u32,
+    badges: u32,
+    no_item: Option<String>,
+    no_badge: Option<String>,
+    group: Vec<String>,
+}
+
+impl Stream for Day3 {
+    type State = Priorities;
+
+    fn read_line(priorities: &mut Priorities, line: &str) -> Result<(), ParseError> {
+        let sack = parse_sack(line)?;
+        priorities.sacks += 1;
+        match shared_item(&sack) {
+            Some(item) => priorities.items += val(item),
+            None => {
+                let sack = priorities.sacks;
+                priorities
+                    .no_item
+                    .get_or_insert_with(|| no_shared_item(sack));
+            }
+        }
+
+        priorities.group.push(sack);
+        if priorities.group.len() == 3 {
+            match badge(&priorities.group) {
+                Some(badge) => priorities.badges += val(badge),
+                None => {
+                    let first = priorities.sacks - 2;
+                    priorities.no_badge.get_or_insert_with(|| no_badge(first));
+                }
+            }
+            priorities.group.clear();
+        }
+        Ok(())
+    }
+
+    fn answer1(priorities: &Priorities) -> Answer {
+        match &priorities.no_item {
+            Some(why) => Answer::failed(why.as_str()),
+            None => priorities.items.into(),
+        }
+    }
+
+    fn answer2(priorities: &Priorities) -> Answer {
+        match (&priorities.no_badge, priorities.group.len()) {
+            (Some(why), _) => Answer::failed(why.as_str()),
+            (None, 0) => priorities.badges.into(),
+            (None, sacks) => Answer::failed(short_group(sacks)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day3>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day3>();
+    }
+
+    #[test]
+    fn rejects_items_that_are_not_letters() {
+        use utils::Solution;
+
+        for (input, place) in [("abcb\nab1b\nabab", (2, 3)), ("abcb\nabc\nabab", (2, 4))] {
+            let Err(err) = super::Day3::parse(input) else {
+                panic!("{input:?} should not parse");
+            };
+            assert_eq!((err.line, err.column), place, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn explains_missing_items_and_badges() {
+        use utils::{
+            stream::{self, Stream},
+            Solution,
+        };
+
+        for (input, part1, part2) in [
+            (
+                "abcb\nabcd\nabab",
+                Err("sack 2 has no item in both halves"),
+                Ok(1),
+            ),
+            (
+                "abab\ncdcd\nefef",
+                Ok(1 + 3 + 5),
+                Err("the group starting at sack 1 has no badge"),
+            ),
+            (
+                "abab\nabab\nabab\nabab",
+                Ok(4),
+                Err("the last group has only 1 of its three sacks"),
+            ),
+        ] {
+            let expected = |part: Result<u32, &str>| match part {
+                Ok(priorities) => priorities.into(),
+                Err(why) => utils::Answer::failed(why),
+            };
+            let sacks = super::Day3::parse(input).unwrap();
+            assert_eq!(super::Day3::part1(&sacks), expected(part1), "{input:?}");
+            assert_eq!(super::Day3::part2(&sacks), expected(part2), "{input:?}");
+
+            let streamed = stream::read::<super::Day3>(input.as_bytes()).unwrap();
+            assert_eq!(
+                super::Day3::answer1(&streamed),
+                expected(part1),
+                "{input:?}"
+            );
+            assert_eq!(
+                super::Day3::answer2(&streamed),
+                expected(part2),
+                "{input:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use utils::{
+    error::parse_at,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub type Pair = ((i32, i32), (i32, i32));
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, section_range_pair)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        question_a(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        question_b(input).into()
+    }
+}
+
+pub fn section_range_pair(line: &str) -> Result<Pair, ParseError> {
+    let (left, right) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::at_end(line, line, "`,` between the ranges"))?;
+    Ok((get_range(line, left)?, get_range(line, right)?))
+}
+
+fn get_range(input: &str, range: &str) -> Result<(i32, i32), ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, range, "a range like `2-4`"))?;
+    Ok((
+        parse_at(input, start, "a section number")?,
+        parse_at(input, end, "a section number")?,
+    ))
+}
+
+fn is_contained(left: (i32, i32), right: (i32, i32)) -> bool {
+    left.0 >= right.0 && left.1 <= right.1
+}
+
+pub fn question_a(pairs: &[Pair]) -> i32 {
+    pairs
+        .iter()
+        .map(|pair| {
+            if is_contained(pair.0, pair.1) || is_contained(pair.1, pair.0) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub fn question_b(pairs: &[Pair]) -> i32 {
+    pairs
+        .iter()
+        .map(|pair| if is_overlapping(pair.0, pair.1) { 1 } else { 0 })
+        .sum()
+}
+
+fn is_overlapping(left: (i32, i32), right: (i32, i32)) -> bool {
+    (left.0 >= right.0 && left.0 <= right.1)
+        || (left.1 >= right.0 && left.1 <= right.1)
+        || is_contained(right, left)
+}
+
+/// How many pairs so far have one range inside the other, and how many overlap at all.
+#[derive(Default)]
+pub struct Overlaps(i32, i32);
+
+impl Stream for Day4 {
+    type State = Overlaps;
+
+    fn read_line(overlaps: &mut Overlaps, line: &str) -> Result<(), ParseError> {
+        let pair = [section_range_pair(line)?];
+        overlaps.0 += question_a(&pair);
+        overlaps.1 += question_b(&pair);
+        Ok(())
+    }
+
+    fn answer1(overlaps: &Overlaps) -> Answer {
+        overlaps.0.into()
+    }
+
+    fn answer2(overlaps: &Overlaps) -> Answer {
+        overlaps.1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day4>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day4>();
+    }
+}
@@ -0,0 +1,219 @@
+use std::io;
+
+use utils::{
+    error::parse_at,
+    grid::Point,
+    render::{self, Frames, Image, Render},
+    simulate::Simulation,
+    Answer, ParseError, Solution,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input = Problem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, false).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, true).into()
+    }
+}
+
+impl Render for Day5 {
+    /// Draws the stacks after each step of the rearrangement in part 1, each crate coloured
+    /// by its letter.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        // Leave a gap between stacks, and room for every crate to end up on one stack.
+        let width = (input.stacks.len() * 2).saturating_sub(1).max(1);
+        let height = input.stacks.iter().map(Vec::len).sum::<usize>().max(1);
+        let draw = |stacks: &[Vec<char>]| {
+            let mut image = Image::new(width, height, render::BLACK);
+            for (i, stack) in stacks.iter().enumerate() {
+                for (level, &letter) in stack.iter().enumerate() {
+                    let colour = render::distinct(letter as usize);
+                    image.set(Point::new(i * 2, height - 1 - level), colour);
+                }
+            }
+            image
+        };
+
+        let mut stacks = input.stacks.clone();
+        frames.push(&draw(&stacks))?;
+        for step in &input.steps {
+            move_crates(&mut stacks, step, false);
+            frames.push(&draw(&stacks))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulation for Day5 {
+    /// The stacks so far, and how many steps of part 1 have been taken.
+    type State = (Vec<Vec<char>>, usize);
+
+    fn start(input: &Self::Input) -> Self::State {
+        (input.stacks.clone(), 0)
+    }
+
+    fn step(input: &Self::Input, (stacks, taken): &mut Self::State) {
+        move_crates(stacks, &input.steps[*taken], false);
+        *taken += 1;
+    }
+
+    /// Draws the stacks the way the puzzle does, then the step to take next.
+    fn render(input: &Self::Input, (stacks, taken): &Self::State) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = (0..height)
+            .rev()
+            .map(|level| {
+                let crates = stacks.iter().map(|stack| match stack.get(level) {
+                    Some(letter) => format!("[{}]", letter),
+                    None => "   ".to_string(),
+                });
+                crates.collect::<Vec<_>>().join(" ").trim_end().to_string()
+            })
+            .collect::<Vec<_>>();
+
+        let numbers = (1..=stacks.len()).map(|n| format!(" {} ", n));
+        lines.push(numbers.collect::<Vec<_>>().join(" "));
+
+        if let Some(step) = input.steps.get(*taken) {
+            lines.push(format!(
+                "\nNext: move {} from {} to {}",
+                step.num_to_move,
+                step.source + 1,
+                step.target + 1
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn done(input: &Self::Input, (_, taken): &Self::State) -> bool {
+        *taken == input.steps.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    stacks: Vec<Vec<char>>,
+    steps: Vec<Step>,
+}
+
+#[derive(Debug)]
+pub struct Step {
+    num_to_move: usize,
+    source: usize,
+    target: usize,
+}
+
+pub fn parse_input(input: &str) -> Result<Problem, ParseError> {
+    let (stacks_str, steps_str) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(
+            input,
+            input,
+            "a blank line between the stacks and the steps",
+        )
+    })?;
+
+    let stack_lines = stacks_str.lines().collect::<Vec<&str>>();
+
+    // last line of stacks section is the stack numbers
+    let (numbers, crate_lines) = stack_lines
+        .split_last()
+        .ok_or_else(|| ParseError::new(1, 1, "a drawing of the stacks", ""))?;
+    let num_stacks = numbers.split_ascii_whitespace().count();
+
+    let mut stacks = vec![vec![]; num_stacks];
+
+    for line in crate_lines {
+        for (stack_num, crate_char) in line.chars().skip(1).step_by(4).enumerate() {
+            if crate_char != ' ' {
+                let stack = stacks.get_mut(stack_num).ok_or_else(|| {
+                    ParseError::at(input, &line[stack_num * 4..], "the end of the stacks")
+                })?;
+                stack.push(crate_char);
+            }
+        }
+    }
+
+    //allows use of push and pop
+    for stack in stacks.iter_mut() {
+        stack.reverse()
+    }
+
+    let steps = steps_str
+        .lines()
+        .map(|line| {
+            let parts = line.split_ascii_whitespace().collect::<Vec<&str>>();
+            if parts.len() != 6 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "a step like `move 1 from 2 to 3`",
+                ));
+            }
+
+            let stack = |part: &str| match parse_at::<usize>(input, part, "a stack number")? {
+                n @ 1.. if n <= num_stacks => Ok(n - 1),
+                _ => Err(ParseError::at(
+                    input,
+                    part,
+                    format!("a stack from 1 to {num_stacks}"),
+                )),
+            };
+
+            Ok(Step {
+                num_to_move: parse_at(input, parts[1], "a number of crates")?,
+                source: stack(parts[3])?,
+                target: stack(parts[5])?,
+            })
+        })
+        .collect::<Result<Vec<Step>, ParseError>>()?;
+
+    Ok(Problem { stacks, steps })
+}
+
+/// The crates left on top after every step, moving several at a time if `at_once`.
+pub fn solve(problem: &Problem, at_once: bool) -> String {
+    let mut stacks = problem.stacks.clone();
+
+    for step in problem.steps.iter() {
+        move_crates(&mut stacks, step, at_once);
+    }
+
+    stacks
+        .into_iter()
+        .map(|stack| stack[stack.len() - 1])
+        .collect::<String>()
+}
+
+/// Moves the crates for `step`, one at a time or all `at_once`.
+pub fn move_crates(stacks: &mut [Vec<char>], step: &Step, at_once: bool) {
+    let source = &mut stacks[step.source];
+    let mut crates_to_move = source.split_off(source.len() - step.num_to_move);
+
+    if !at_once {
+        crates_to_move.reverse();
+    }
+
+    stacks[step.target].extend_from_slice(&crates_to_move);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day5>();
+    }
+}
@@ -0,0 +1,105 @@
+use utils::{Answer, ParseError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let buffer = input.trim();
+        if let Some((at, c)) = buffer.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            return Err(ParseError::at(
+                input,
+                &buffer[at..at + c.len_utf8()],
+                "a letter from `a` to `z`",
+            ));
+        }
+        Ok(buffer.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        match part_a(input) {
+            Some(end) => end.into(),
+            None => Answer::failed("no start-of-packet marker, 4 different letters in a row"),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match part_b(input) {
+            Some(end) => end.into(),
+            None => Answer::failed("no start-of-message marker, 14 different letters in a row"),
+        }
+    }
+}
+
+/// Where the start-of-packet marker ends, if there is one.
+pub fn part_a(input: &str) -> Option<usize> {
+    const WINDOW_SIZE: usize = 4;
+    input
+        .as_bytes()
+        .windows(WINDOW_SIZE)
+        .position(|s| {
+            (s[0] != s[1] && s[0] != s[2] && s[0] != s[3])
+                && (s[1] != s[2] && s[1] != s[3])
+                && (s[2] != s[3])
+        })
+        .map(|position| position + WINDOW_SIZE)
+}
+
+/// Where the start-of-message marker ends, if there is one.
+pub fn part_b(input: &str) -> Option<usize> {
+    const WINDOW_SIZE: usize = 14;
+    input
+        .as_bytes()
+        .windows(WINDOW_SIZE)
+        .position(|s| {
+            let mut seen = [false; 26]; //assumes only a-z
+            for &e in s {
+                let c = (e - b'a') as usize;
+                if seen[c] {
+                    return false;
+                }
+                seen[c] = true;
+            }
+            true
+        })
+        .map(|position| position + WINDOW_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day6>();
+    }
+
+    #[test]
+    fn rejects_buffers_that_are_not_letters() {
+        use utils::Solution;
+
+        for (input, column) in [("abcD", 4), ("abcd\nefgh", 5)] {
+            let Err(err) = super::Day6::parse(input) else {
+                panic!("{input:?} should not parse");
+            };
+            assert_eq!((err.line, err.column), (1, column), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn answers_each_part_with_its_own_marker() {
+        use utils::Solution;
+
+        let buffer = super::Day6::parse("abcdefg").unwrap();
+        assert_eq!(super::Day6::part1(&buffer), 4u32.into());
+        assert_eq!(
+            super::Day6::part2(&buffer).failure(),
+            Some("no start-of-message marker, 14 different letters in a row")
+        );
+        let buffer = super::Day6::parse("aaaa").unwrap();
+        assert!(super::Day6::part1(&buffer).failure().is_some());
+    }
+}
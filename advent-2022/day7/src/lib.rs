@@ -0,0 +1,129 @@
+use regex::Regex;
+
+use utils::{error::parse_at, Answer, ParseError, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Directory>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_a(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_b(input).into()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    size: usize,
+    parent: usize,
+}
+
+const DISK_SIZE: usize = 70000000;
+
+pub fn parse(input: &str) -> Result<Vec<Directory>, ParseError> {
+    //parent is the index in the vec of the parent Directory
+    //size is the accumulative size of all files and directories beneath
+
+    let mut directories = vec![Directory {
+        name: "/".to_string(),
+        size: 0,
+        parent: 0,
+    }];
+
+    let root = 0;
+    let mut cwd = root;
+
+    //only care about the changing of the dir
+    let dir_rx = Regex::new(r"\s*\$ cd (?P<target>.+)").unwrap();
+    //and files for their size
+    let file_rx = Regex::new(r"\s*(?P<size>\d+)\s+(?P<file>.+)").unwrap();
+
+    for line in input.lines() {
+        if let Some(cap) = dir_rx.captures(line) {
+            match &cap["target"] {
+                "/" => cwd = root,
+                ".." => cwd = directories[cwd].parent,
+                name => {
+                    directories.push(Directory {
+                        name: String::from(name),
+                        size: 0,
+                        parent: cwd,
+                    });
+                    cwd = directories.len() - 1;
+                }
+            }
+        } else if let Some(cap) = file_rx.captures(line) {
+            //could store files but no need
+            let size: usize = parse_at(input, &cap["size"], "a file size")?;
+            if size > DISK_SIZE - directories[root].size {
+                return Err(ParseError::at(
+                    input,
+                    &cap["size"],
+                    format!("files adding up to at most {DISK_SIZE}, the size of the disk"),
+                ));
+            }
+
+            let mut p = cwd;
+            loop {
+                directories[p].size += size;
+                if p == root {
+                    break;
+                }
+                p = directories[p].parent;
+            }
+        }
+    }
+    Ok(directories)
+}
+
+pub fn part_a(file_sys: &[Directory]) -> usize {
+    file_sys
+        .iter()
+        .map(|d| d.size)
+        .filter(|size| *size <= 100000)
+        .sum()
+}
+
+pub fn part_b(file_sys: &[Directory]) -> usize {
+    // `parse` makes sure the files fit on the disk.
+    let free_space = DISK_SIZE - file_sys[0].size;
+    let need_to_free = 30000000usize.saturating_sub(free_space);
+
+    file_sys
+        .iter()
+        .map(|d| d.size)
+        .filter(|size| *size >= need_to_free)
+        .min()
+        // The root holds every file, so deleting it always frees enough.
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day7>();
+    }
+
+    #[test]
+    fn rejects_files_too_big_for_the_disk() {
+        let err = super::parse("$ cd /\n$ ls\n40000000 a\n30000001 b\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (4, 1, "30000001")
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use utils::{stream::Stream, Answer, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    /// The calibration document, one line per entry.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|line| question_a(line))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|line| question_b(line))
+            .sum::<u32>()
+            .into()
+    }
+}
+
+pub fn question_a(line: &str) -> u32 {
+    let digits = line
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+
+    digits.first().unwrap_or(&0) * 10 + digits.last().unwrap_or(&0)
+}
+
+pub fn question_b(line: &str) -> u32 {
+    let digit_words = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut transformed = String::new();
+    let mut i = 0;
+
+    while i < line.len() {
+        if let Some((digit, word)) = digit_words
+            .iter()
+            .enumerate()
+            .find(|(_, &word)| line[i..].starts_with(word))
+        {
+            transformed.push(((digit + 1) as u8 + b'0') as char);
+            i += word.len() - 1;
+        } else {
+            transformed.push(line[i..].chars().next().unwrap());
+            i += 1;
+        }
+    }
+
+    question_a(&transformed)
+}
+
+/// The calibration values summed so far, reading digits alone and then spelled out too.
+#[derive(Default)]
+pub struct Calibration(u32, u32);
+
+impl Stream for Day1 {
+    type State = Calibration;
+
+    fn read_line(calibration: &mut Calibration, line: &str) -> Result<(), ParseError> {
+        calibration.0 += question_a(line);
+        calibration.1 += question_b(line);
+        Ok(())
+    }
+
+    fn answer1(calibration: &Calibration) -> Answer {
+        calibration.0.into()
+    }
+
+    fn answer2(calibration: &Calibration) -> Answer {
+        calibration.1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day1>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day1>();
+    }
+}
@@ -0,0 +1,154 @@
+use utils::{geometry::Point, Answer, ParseError, Solution};
+
+pub fn part_1(galaxies: &[Point]) -> usize {
+    solve(&expand(galaxies, 2))
+}
+pub fn part_2(galaxies: &[Point]) -> usize {
+    solve(&expand(galaxies, 1000000))
+}
+
+fn solve(galaxies: &[Point]) -> usize {
+    let mut sum = 0;
+
+    for i in 0..galaxies.len() {
+        for &b in &galaxies[i + 1..] {
+            sum += galaxies[i].manhattan(b);
+        }
+    }
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    let mut galaxies: Vec<Point> = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '#' => galaxies.push(Point::new(x, y)),
+                '.' => {}
+                _ => return Err(ParseError::new(y + 1, x + 1, "`#` or `.`", c)),
+            }
+        }
+    }
+
+    Ok(galaxies)
+}
+
+/// Moves every galaxy to where it ends up once each empty row and column has grown to
+/// `expansion` rows or columns. `galaxies` must be in the order `parse` found them.
+pub fn expand(galaxies: &[Point], expansion: usize) -> Vec<Point> {
+    let mut galaxies = galaxies.to_vec();
+
+    // Every row between the last galaxy and this one is empty, including the rows above the
+    // first galaxy.
+    let mut sum_expansion = 0;
+    let mut next_y = 0;
+    for galaxy in galaxies.iter_mut() {
+        sum_expansion += galaxy.y.saturating_sub(next_y) * (expansion - 1);
+        next_y = galaxy.y + 1;
+        galaxy.y += sum_expansion;
+    }
+
+    galaxies.sort_by_key(|galaxy| galaxy.x);
+
+    sum_expansion = 0;
+    let mut next_x = 0;
+    for galaxy in galaxies.iter_mut() {
+        sum_expansion += galaxy.x.saturating_sub(next_x) * (expansion - 1);
+        next_x = galaxy.x + 1;
+        galaxy.x += sum_expansion
+    }
+
+    galaxies
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    /// Galaxy positions before the universe expands, in reading order.
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn answers() {
+        utils::expected::check::<Day11>();
+    }
+
+    /// Grows the image by inserting `expansion - 1` copies of every empty row and column.
+    fn expand_by_copying(image: &[Vec<bool>], expansion: usize) -> Vec<(usize, usize)> {
+        let empty_row = |y: usize| image[y].iter().all(|&galaxy| !galaxy);
+        let empty_column = |x: usize| image.iter().all(|row| !row[x]);
+
+        let mut rows = Vec::new();
+        for y in 0..image.len() {
+            let copies = if empty_row(y) { expansion } else { 1 };
+            rows.extend((0..copies).map(|_| y));
+        }
+        let mut columns = Vec::new();
+        for x in 0..image[0].len() {
+            let copies = if empty_column(x) { expansion } else { 1 };
+            columns.extend((0..copies).map(|_| x));
+        }
+
+        let mut galaxies = Vec::new();
+        for (new_y, &y) in rows.iter().enumerate() {
+            for (new_x, &x) in columns.iter().enumerate() {
+                if image[y][x] {
+                    galaxies.push((new_x, new_y));
+                }
+            }
+        }
+        galaxies.sort();
+        galaxies
+    }
+
+    fn image() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::bool::weighted(0.2), width),
+                height,
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn expansion_matches_copying_empty_lines(image in image(), expansion in 1..5usize) {
+            let text = image
+                .iter()
+                .map(|row| row.iter().map(|&g| if g { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            let galaxies = parse(&text).unwrap();
+            let mut expanded = expand(&galaxies, expansion)
+                .iter()
+                .map(|galaxy| (galaxy.x, galaxy.y))
+                .collect::<Vec<_>>();
+            expanded.sort();
+
+            prop_assert_eq!(expanded, expand_by_copying(&image, expansion));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use utils::{error::parse_at, Answer, ParseError, Solution};
+
+pub type Input = Vec<(Vec<u8>, Vec<usize>)>;
+type Map = HashMap<(usize, usize), u64>;
+
+/// Counts the arrangements of every row, each unfolded into `repeat + 1` copies.
+pub fn solve(input: &Input, repeat: usize) -> u64 {
+    let mut result = 0;
+    let mut bytes = Vec::new();
+    let mut nums = Vec::new();
+    let mut cache = HashMap::new();
+
+    for (first, second) in input {
+        // Handles Part 2
+        for _ in 0..repeat {
+            bytes.extend(first);
+            bytes.push(b'?');
+            nums.extend(second);
+        }
+
+        bytes.extend(first);
+        bytes.push(b'.');
+        nums.extend(second);
+
+        let mut sum = 0;
+        let mut ps = vec![0; nums.len()];
+
+        for i in (1..nums.len()).rev() {
+            sum += nums[i] + 1;
+            ps[i - 1] = sum;
+        }
+
+        result += helper(&bytes, &nums, &ps, &mut cache);
+
+        bytes.clear();
+        nums.clear();
+        cache.clear();
+    }
+
+    result
+}
+
+fn helper(slice: &[u8], nums: &[usize], ps: &[usize], cache: &mut Map) -> u64 {
+    let key = (slice.len(), nums.len());
+    if let Some(prev) = cache.get(&key) {
+        return *prev;
+    }
+
+    if nums.is_empty() {
+        let result = working(slice) as u64;
+        cache.insert(key, result);
+        return result;
+    }
+
+    let size = nums[0];
+    // Too little room left for the groups means there is no arrangement.
+    let Some(wiggle) = slice.len().checked_sub(ps[0] + size) else {
+        cache.insert(key, 0);
+        return 0;
+    };
+    let mut result = 0;
+
+    for offset in 0..wiggle {
+        if offset > 0 && slice[offset - 1] == b'#' {
+            break;
+        }
+        if slice[offset + size] != b'#' && broken(&slice[offset..offset + size]) {
+            result += helper(&slice[offset + size + 1..], &nums[1..], &ps[1..], cache);
+        }
+    }
+
+    cache.insert(key, result);
+    result
+}
+
+fn working(slice: &[u8]) -> bool {
+    slice.iter().all(|&b| b == b'.' || b == b'?')
+}
+
+fn broken(slice: &[u8]) -> bool {
+    slice.iter().all(|&b| b == b'#' || b == b'?')
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (prefix, suffix) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at_end(input, line, "group sizes"))?;
+            if let Some(i) = prefix.find(|c| !matches!(c, '.' | '#' | '?')) {
+                return Err(ParseError::at(input, &prefix[i..i + 1], "`.`, `#` or `?`"));
+            }
+            let first = prefix.as_bytes().to_vec();
+            let second = suffix
+                .split(',')
+                .map(|s| parse_at::<usize>(input, s, "a group size"))
+                .collect::<Result<_, _>>()?;
+            Ok((first, second))
+        })
+        .collect()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve(input, 0).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve(input, 4).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn answers() {
+        utils::expected::check::<Day12>();
+    }
+
+    /// The sizes of the groups of damaged springs in `row`.
+    fn groups(row: &[u8]) -> Vec<usize> {
+        row.split(|&b| b == b'.')
+            .map(<[u8]>::len)
+            .filter(|&len| len > 0)
+            .collect()
+    }
+
+    /// Counts the arrangements by trying every way of filling in the unknown springs.
+    fn count_every_arrangement(row: &[u8], nums: &[usize]) -> u64 {
+        let unknown = row
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'?')
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let mut count = 0;
+        let mut filled = row.to_vec();
+        for mask in 0..1u32 << unknown.len() {
+            for (bit, &i) in unknown.iter().enumerate() {
+                filled[i] = if mask >> bit & 1 == 1 { b'#' } else { b'.' };
+            }
+            if groups(&filled) == nums {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// A row of springs with some of them unknown, and the group sizes of the row before
+    /// they were hidden, so there is always at least one arrangement.
+    fn solvable_record() -> impl Strategy<Value = (Vec<u8>, Vec<usize>)> {
+        prop::collection::vec((prop::bool::ANY, prop::bool::weighted(0.5)), 1..14).prop_map(
+            |springs| {
+                let row = springs
+                    .iter()
+                    .map(|&(damaged, _)| if damaged { b'#' } else { b'.' })
+                    .collect::<Vec<_>>();
+                let nums = groups(&row);
+                let hidden = row
+                    .iter()
+                    .zip(&springs)
+                    .map(|(&b, &(_, hide))| if hide { b'?' } else { b })
+                    .collect();
+                (hidden, nums)
+            },
+        )
+    }
+
+    /// A row of springs with some of them unknown, and any group sizes at all, which may
+    /// not fit the row.
+    fn any_record() -> impl Strategy<Value = (Vec<u8>, Vec<usize>)> {
+        (
+            prop::collection::vec(prop::sample::select(b".#?".to_vec()), 1..14),
+            prop::collection::vec(1..6usize, 0..5),
+        )
+    }
+
+    fn record() -> impl Strategy<Value = (Vec<u8>, Vec<usize>)> {
+        prop_oneof![solvable_record(), any_record()]
+    }
+
+    #[test]
+    fn counts_no_arrangements_when_the_groups_do_not_fit() {
+        let input = parse(".. 5\n#. 1,1\n??? 2,2").unwrap();
+        for row in input {
+            assert_eq!(solve(&vec![row.clone()], 0), 0, "{row:?}");
+            assert_eq!(solve(&vec![row.clone()], 4), 0, "{row:?}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn arrangements_match_trying_every_one((row, nums) in record()) {
+            let expected = count_every_arrangement(&row, &nums);
+            prop_assert_eq!(solve(&vec![(row, nums)], 0), expected);
+        }
+
+        #[test]
+        fn unfolded_arrangements_match_trying_every_one((row, nums) in record()) {
+            // Unfolding once doubles the length, so keep the rows short enough to try.
+            prop_assume!(row.len() <= 7);
+
+            let mut unfolded = row.clone();
+            unfolded.push(b'?');
+            unfolded.extend(&row);
+            let expected = count_every_arrangement(&unfolded, &nums.repeat(2));
+            prop_assert_eq!(solve(&vec![(row, nums)], 1), expected);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use utils::{grid::Grid, Answer, ParseError, Solution};
+
+fn test_row(grid: &Grid<char>, index: usize) -> bool {
+    let height = usize::min(index + 1, grid.height() - index - 1);
+
+    (0..height).all(|i| {
+        let row_above = grid.row(index - i);
+        let row_below = grid.row(index + i + 1);
+
+        row_above == row_below
+    })
+}
+
+fn test_col(grid: &Grid<char>, index: usize) -> bool {
+    let width = usize::min(index + 1, grid.width() - index - 1);
+
+    (0..width).all(|i| {
+        let col_left = grid.column(index - i);
+        let col_right = grid.column(index + i + 1);
+
+        col_left.eq(col_right)
+    })
+}
+
+fn process_grid(grid: &Grid<char>) -> Option<usize> {
+    let row = (0..grid.height().saturating_sub(1)).find_map(|i| {
+        if test_row(grid, i) {
+            Some(100 * (i + 1))
+        } else {
+            None
+        }
+    });
+    let col = (0..grid.width().saturating_sub(1)).find_map(|i| {
+        if test_col(grid, i) {
+            Some(i + 1)
+        } else {
+            None
+        }
+    });
+
+    row.or(col)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|grid| {
+            Grid::parse(grid, |c| matches!(c, '#' | '.').then_some(c))
+                .map_err(|err| err.expecting("`#` or `.`").within(input, grid))
+        })
+        .collect()
+}
+
+/// Adds up the summary of each pattern's line of reflection, found by `process`, or says
+/// which pattern, counting from 1, is the first to have none.
+fn summarise(
+    grids: &[Grid<char>],
+    process: fn(&Grid<char>) -> Option<usize>,
+    line: &str,
+) -> Result<usize, String> {
+    grids
+        .iter()
+        .enumerate()
+        .map(|(i, grid)| process(grid).ok_or_else(|| format!("pattern {} has no {line}", i + 1)))
+        .sum()
+}
+
+pub fn part_1(grids: &[Grid<char>]) -> Result<usize, String> {
+    summarise(grids, process_grid, "line of reflection")
+}
+
+fn diff_row(grid: &Grid<char>, index: usize) -> usize {
+    let height = usize::min(index + 1, grid.height() - index - 1);
+
+    (0..height)
+        .map(|i| {
+            let row_above = grid.row(index - i);
+            let row_below = grid.row(index + i + 1);
+
+            Iterator::zip(row_above.iter(), row_below.iter())
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+fn diff_col(grid: &Grid<char>, index: usize) -> usize {
+    let width = usize::min(index + 1, grid.width() - index - 1);
+
+    (0..width)
+        .map(|i| {
+            let col_left = grid.column(index - i);
+            let col_right = grid.column(index + i + 1);
+
+            Iterator::zip(col_left, col_right)
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+fn process_grid_2(grid: &Grid<char>) -> Option<usize> {
+    let row = (0..grid.height().saturating_sub(1)).find_map(|i| {
+        if diff_row(grid, i) == 1 {
+            Some(100 * (i + 1))
+        } else {
+            None
+        }
+    });
+    let col = (0..grid.width().saturating_sub(1)).find_map(|i| {
+        if diff_col(grid, i) == 1 {
+            Some(i + 1)
+        } else {
+            None
+        }
+    });
+
+    row.or(col)
+}
+
+pub fn part_2(grids: &[Grid<char>]) -> Result<usize, String> {
+    summarise(
+        grids,
+        process_grid_2,
+        "line of reflection after fixing one smudge",
+    )
+}
+
+fn answer(summary: Result<usize, String>) -> Answer {
+    match summary {
+        Ok(summary) => summary.into(),
+        Err(why) => Answer::failed(why),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    /// Every pattern of ash and rocks.
+    type Input = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        answer(part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        answer(part_2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day13>();
+    }
+
+    #[test]
+    fn explains_patterns_without_reflections() {
+        let grids = super::parse("##\n##\n#.\n\n#.#\n.#.\n##.\n").unwrap();
+        assert_eq!(
+            super::part_1(&grids),
+            Err("pattern 2 has no line of reflection".to_string())
+        );
+
+        // Mirrored down the middle, but with no smudge to fix.
+        let grids = super::parse("##\n..\n").unwrap();
+        assert_eq!(super::part_1(&grids), Ok(1));
+        assert_eq!(
+            super::part_2(&grids),
+            Err("pattern 1 has no line of reflection after fixing one smudge".to_string())
+        );
+    }
+}
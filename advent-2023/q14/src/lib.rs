@@ -0,0 +1,187 @@
+use std::{collections::HashSet, fmt, io};
+
+use utils::{
+    cycle,
+    grid::{Grid, Point},
+    render::{self, Frames, Image, Render},
+    simulate::Simulation,
+    Answer, ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Round,
+    Square,
+    Empty,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Tile::Round => 'O',
+            Tile::Square => '#',
+            Tile::Empty => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(Tile::Empty),
+        '#' => Some(Tile::Square),
+        'O' => Some(Tile::Round),
+        _ => None,
+    })
+    .map_err(|err| err.expecting("`.`, `#` or `O`"))
+}
+
+pub fn slide_north(grid: &mut Grid<Tile>) {
+    for col in 0..grid.width() {
+        let mut empty_or_round_row = 0;
+        for row in 0..grid.height() {
+            let curr = grid[Point::new(col, row)];
+            match curr {
+                Tile::Square => empty_or_round_row = row + 1,
+                Tile::Round => {
+                    // swap the current tile with the empty_or_round one
+                    let replace_with =
+                        std::mem::replace(&mut grid[Point::new(col, empty_or_round_row)], curr);
+                    grid[Point::new(col, row)] = replace_with;
+                    empty_or_round_row += 1;
+                }
+                Tile::Empty => (),
+            }
+        }
+    }
+}
+
+/// The total load the round rocks put on the north beams.
+pub fn weight(grid: &Grid<Tile>) -> usize {
+    grid.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, row)| {
+            let round_rocks = row.iter().filter(|tile| **tile == Tile::Round).count();
+            round_rocks * (i + 1)
+        })
+        .sum()
+}
+
+/// Tilts the platform north, west, south and east in turn.
+#[inline]
+pub fn spin_cycle(grid: &mut Grid<Tile>) {
+    for _ in 0..4 {
+        slide_north(grid);
+        *grid = grid.rotate_clockwise();
+    }
+}
+
+pub fn part_1(grid: &Grid<Tile>) -> usize {
+    let mut grid = grid.clone();
+    slide_north(&mut grid);
+    weight(&grid)
+}
+
+pub fn part_2(grid: &Grid<Tile>) -> usize {
+    // Only a hash of each position is kept, as the platforms are large.
+    let grid = cycle::nth_by_key(grid.clone(), 1_000_000_000, spin_cycle, cycle::fingerprint);
+    weight(&grid)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+impl Render for Day14 {
+    /// Draws the platform after every tilt of the spin cycles, until it is back in a
+    /// position it has been in before.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        let draw = |grid: &Grid<Tile>| {
+            Image::from_grid(grid, |tile| match tile {
+                Tile::Round => render::YELLOW,
+                Tile::Square => render::GREY,
+                Tile::Empty => render::BLACK,
+            })
+        };
+
+        let mut grid = input.clone();
+        let mut seen = HashSet::from([grid.clone()]);
+        frames.push(&draw(&grid))?;
+
+        loop {
+            // Tilting always slides north, so turn the grid back before drawing it.
+            for turns in 1..=4 {
+                slide_north(&mut grid);
+                grid = grid.rotate_clockwise();
+                let mut upright = grid.clone();
+                for _ in turns..4 {
+                    upright = upright.rotate_clockwise();
+                }
+                frames.push(&draw(&upright))?;
+            }
+
+            if !seen.insert(grid.clone()) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The platform part way through its spin cycles, and every position it has been in.
+pub struct Spinning {
+    grid: Grid<Tile>,
+    seen: HashSet<Grid<Tile>>,
+}
+
+impl Simulation for Day14 {
+    type State = Spinning;
+
+    fn start(input: &Self::Input) -> Spinning {
+        Spinning {
+            grid: input.clone(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Runs one whole spin cycle.
+    fn step(_: &Self::Input, state: &mut Spinning) {
+        state.seen.insert(state.grid.clone());
+        spin_cycle(&mut state.grid);
+    }
+
+    fn render(_: &Self::Input, state: &Spinning) -> String {
+        format!("Load: {}\n{}", weight(&state.grid), state.grid)
+    }
+
+    /// Done once the platform is back in a position it has been in before, as from there
+    /// it only repeats itself.
+    fn done(_: &Self::Input, state: &Spinning) -> bool {
+        state.seen.contains(&state.grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day14>();
+    }
+}
@@ -0,0 +1,125 @@
+use regex::Regex;
+
+use utils::{error::parse_at, Answer, ParseError, Solution};
+
+/// One step of the initialization sequence: its text, for hashing, and the lens it
+/// removes or inserts with a focal length.
+pub struct Step {
+    text: String,
+    lens: String,
+    power: Option<usize>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+    let pattern_re: Regex = Regex::new(r"^(\w+)(?:-|=(\d))$").unwrap();
+
+    input
+        .trim()
+        .split(',')
+        .map(|step| {
+            let caps = pattern_re
+                .captures(step)
+                .ok_or_else(|| ParseError::at(input, step, "a step like `rn=1` or `cm-`"))?;
+
+            // `\d` matches digits from any script, which `parse` does not read.
+            let power = caps
+                .get(2)
+                .map(|power| parse_at(input, power.as_str(), "a focal length from 0 to 9"))
+                .transpose()?;
+
+            Ok(Step {
+                text: step.to_string(),
+                lens: caps[1].to_string(),
+                power,
+            })
+        })
+        .collect()
+}
+
+pub fn part_1(steps: &[Step]) -> u32 {
+    steps.iter().map(|s| hash(&mut s.text.chars())).sum()
+}
+
+pub fn hash<I: Iterator<Item = char>>(input: &mut I) -> u32 {
+    input.fold(0, |acc, c| ((acc + (c as u32)) * 17) % 256)
+}
+
+pub fn part_2(steps: &[Step]) -> usize {
+    const VAL: Vec<(String, usize)> = vec![];
+    let mut boxes: [Vec<(String, usize)>; 256] = [VAL; 256];
+
+    steps.iter().for_each(|ins| {
+        let lens = ins.lens.as_str();
+
+        let box_index = hash(&mut lens.chars());
+
+        let valid_box = &mut boxes[box_index as usize];
+        if let Some(power) = ins.power {
+            if let Some(found_index) = valid_box
+                .iter()
+                .position(|(lens_in_box, _)| lens_in_box == lens)
+            {
+                valid_box[found_index] = (lens.to_string(), power);
+            } else {
+                valid_box.push((lens.to_string(), power));
+            }
+        } else {
+            valid_box.retain(|(lens_in_box, _)| lens_in_box != lens);
+        }
+    });
+
+    (0..256usize)
+        .map(|box_idx| {
+            boxes[box_idx]
+                .iter()
+                .enumerate()
+                .map(|(slot, (_, power))| (slot + 1) * *power * (box_idx + 1))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    /// The comma separated steps of the initialization sequence.
+    type Input = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day15>();
+    }
+
+    #[test]
+    fn rejects_other_digits() {
+        let Err(err) = super::parse("rn=1,cm=\u{663}") else {
+            panic!("an Arabic-Indic digit should not parse");
+        };
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (1, 9, "\u{663}")
+        );
+    }
+    #[test]
+    fn hash_test() {
+        assert_eq!(30, super::hash(&mut "rn=1".chars()));
+    }
+}
@@ -0,0 +1,233 @@
+use std::io;
+
+use utils::{
+    grid::{Direction, Grid, Point},
+    render::{self, Frames, Image, Render},
+    simulate::Simulation,
+    Answer, ParseError, Solution,
+};
+
+pub enum Tile {
+    Empty,
+    Mirror(bool),
+    Splitter(bool),
+}
+
+pub fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '\\' => Some(Tile::Mirror(false)),
+        '/' => Some(Tile::Mirror(true)),
+        '|' => Some(Tile::Splitter(false)),
+        '-' => Some(Tile::Splitter(true)),
+        '.' => Some(Tile::Empty),
+        _ => None,
+    })
+    .map_err(|err| err.expecting("`.`, `/`, `\\`, `|` or `-`"))
+}
+
+/// How many tiles a beam entering at `start` energises.
+pub fn solve(board: &Grid<Tile>, start: (Point, Direction)) -> usize {
+    energise(board, start, |_| ())
+        .iter()
+        .filter(|n| **n)
+        .count()
+}
+
+/// Beams of light spreading through the grid from where one enters, followed a tile at a
+/// time.
+pub struct Beams {
+    /// Where the beam being followed is, and which way it is going.
+    head: Option<(Point, Direction)>,
+    /// Beams that split off, waiting to be followed later, by where they start.
+    queue: Vec<(Point, Direction)>,
+    /// The tiles passed through going each way, then going any way.
+    visited: Vec<Grid<bool>>,
+}
+
+impl Beams {
+    pub fn new(board: &Grid<Tile>, start: (Point, Direction)) -> Self {
+        Beams {
+            head: None,
+            queue: vec![start],
+            visited: vec![Grid::new(board.width(), board.height(), false); 5],
+        }
+    }
+
+    /// Whether every beam has left the grid or met a path already taken.
+    pub fn done(&self) -> bool {
+        self.head.is_none() && self.queue.is_empty()
+    }
+
+    pub fn energised(&self) -> &Grid<bool> {
+        &self.visited[4]
+    }
+
+    /// Moves the beam being followed on by a tile, or starts on the next one, returning
+    /// whether it has just ended.
+    pub fn step(&mut self, board: &Grid<Tile>) -> bool {
+        let Some((point, mut direction)) = self.head.take().or_else(|| self.queue.pop()) else {
+            return false;
+        };
+        if self.visited[direction as usize][point] {
+            return true;
+        }
+
+        self.visited[4][point] = true;
+        self.visited[direction as usize][point] = true;
+
+        let vertical = matches!(direction, Direction::North | Direction::South);
+        match board[point] {
+            // `/` turns beams going up or down to the right, and `\` to the left.
+            Tile::Mirror(forward) => {
+                direction = if forward == vertical {
+                    direction.turn_right()
+                } else {
+                    direction.turn_left()
+                };
+            }
+            // `|` splits beams going across, and `-` beams going up or down.
+            Tile::Splitter(across) if across == vertical => {
+                direction = direction.turn_left();
+                self.queue.push((point, direction.reverse()));
+            }
+            _ => {}
+        }
+
+        self.head = board.go(point, direction).map(|next| (next, direction));
+        self.head.is_none()
+    }
+}
+
+/// Follows the beam entering at `start` through every split, calling `traced` with the
+/// tiles energised so far each time a beam leaves the grid or meets a path already taken.
+pub fn energise(
+    board: &Grid<Tile>,
+    start: (Point, Direction),
+    mut traced: impl FnMut(&Grid<bool>),
+) -> Grid<bool> {
+    let mut beams = Beams::new(board, start);
+    while !beams.done() {
+        if beams.step(board) {
+            traced(beams.energised());
+        }
+    }
+
+    beams.visited.swap_remove(4)
+}
+
+pub fn part_1(map: &Grid<Tile>) -> usize {
+    solve(map, (Point::new(0, 0), Direction::East))
+}
+
+pub fn part_2(map: &Grid<Tile>) -> usize {
+    let mut max = 0;
+
+    for i in 0..map.height() {
+        max = max.max(solve(map, (Point::new(0, i), Direction::East)));
+        max = max.max(solve(
+            map,
+            (Point::new(map.width() - 1, i), Direction::West),
+        ));
+    }
+
+    for i in 0..map.width() {
+        max = max.max(solve(map, (Point::new(i, 0), Direction::South)));
+        max = max.max(solve(
+            map,
+            (Point::new(i, map.height() - 1), Direction::North),
+        ));
+    }
+
+    max
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+impl Render for Day16 {
+    /// Draws the tiles energised by the beam from the top left corner, adding each branch
+    /// of the beam in turn.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        let mut result = Ok(());
+        energise(input, (Point::new(0, 0), Direction::East), |energised| {
+            let mut image = Image::from_grid(input, |tile| match tile {
+                Tile::Empty => render::BLACK,
+                Tile::Mirror(_) | Tile::Splitter(_) => render::GREY,
+            });
+            let lit = energised.points().filter(|&point| energised[point]);
+            image.overlay(lit, render::YELLOW);
+
+            if result.is_ok() {
+                result = frames.push(&image);
+            }
+        });
+        result
+    }
+}
+
+impl Simulation for Day16 {
+    type State = Beams;
+
+    fn start(input: &Self::Input) -> Beams {
+        Beams::new(input, (Point::new(0, 0), Direction::East))
+    }
+
+    fn step(input: &Self::Input, beams: &mut Beams) {
+        beams.step(input);
+    }
+
+    /// Draws energised tiles as `#`, and the beam being followed as the way it is going.
+    fn render(input: &Self::Input, beams: &Beams) -> String {
+        let tiles = input
+            .points()
+            .map(|point| match (beams.head, &input[point]) {
+                (Some((head, direction)), _) if head == point => direction.to_string(),
+                (_, Tile::Mirror(false)) => "\\".to_string(),
+                (_, Tile::Mirror(true)) => "/".to_string(),
+                (_, Tile::Splitter(false)) => "|".to_string(),
+                (_, Tile::Splitter(true)) => "-".to_string(),
+                (_, Tile::Empty) if beams.energised()[point] => "#".to_string(),
+                (_, Tile::Empty) => ".".to_string(),
+            })
+            .collect();
+        let grid = Grid::from_vec(tiles, input.width());
+        let energised = beams.energised().iter().filter(|&&lit| lit).count();
+
+        format!(
+            "Energised: {}, beams waiting: {}\n{}",
+            energised,
+            beams.queue.len(),
+            grid
+        )
+    }
+
+    fn done(_: &Self::Input, beams: &Beams) -> bool {
+        beams.done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day16>();
+    }
+}
@@ -0,0 +1,150 @@
+use std::io;
+
+use utils::{
+    graph,
+    grid::{Direction, Grid, Point},
+    render::{self, Frames, Image, Render},
+    Answer, ParseError, Solution,
+};
+
+/// Finds the least heat lost getting a crucible from the top left to the bottom right,
+/// calling `reached` with every tile a crucible turns on and the heat it has lost so far.
+pub fn dijkstra(
+    grid: &Grid<usize>,
+    min_consecutive: usize,
+    max_consecutive: usize,
+    mut reached: impl FnMut(Point, usize),
+) -> Option<usize> {
+    let end = Point::new(grid.width() - 1, grid.height() - 1);
+
+    // Each state is a tile and the way the crucible was going when it got there, so its
+    // next move is to turn and go between `min_consecutive` and `max_consecutive` tiles.
+    // Setting off east or south is the same as turning after arriving the other way.
+    let origin = Point::new(0, 0);
+    let starts = [(origin, Direction::South), (origin, Direction::East)];
+    let moves = |&(point, direction): &(Point, Direction)| {
+        let mut moves = Vec::new();
+        for direction in [direction.turn_left(), direction.turn_right()] {
+            let (mut point, mut heat_loss) = (point, 0);
+            for step in 1..=max_consecutive {
+                let Some(next) = grid.go(point, direction) else {
+                    break;
+                };
+                point = next;
+                heat_loss += grid[point];
+                if step >= min_consecutive {
+                    moves.push(((point, direction), heat_loss));
+                }
+            }
+        }
+        moves
+    };
+
+    graph::dijkstra(starts, moves)
+        .inspect(|&((point, _), heat_loss)| reached(point, heat_loss))
+        .find(|&((point, _), _)| point == end)
+        .map(|(_, heat_loss)| heat_loss)
+}
+
+pub fn part_1(grid: &Grid<usize>) -> Option<usize> {
+    dijkstra(grid, 0, 3, |_, _| ())
+}
+
+/// Ultra crucibles cannot stop within four tiles of turning, so on some small grids they
+/// never reach the end.
+pub fn part_2(grid: &Grid<usize>) -> Option<usize> {
+    dijkstra(grid, 4, 10, |_, _| ())
+}
+
+/// The answer to a part, or why there is none.
+fn heat_loss_answer(heat_loss: Option<usize>) -> Answer {
+    match heat_loss {
+        Some(heat_loss) => heat_loss.into(),
+        None => Answer::failed("no way for the crucible to reach the bottom right"),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|digit| digit as usize))
+        .map_err(|err| err.expecting("a digit"))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        heat_loss_answer(part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        heat_loss_answer(part_2(input))
+    }
+}
+
+impl Render for Day17 {
+    /// Draws the part 1 search spreading out from the top left corner, colouring each tile
+    /// by the least heat lost reaching it, from blue for none to red for the answer.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        let answer = part_1(input).map_or(1, |answer| answer.max(1));
+        // About a hundred frames, whatever the size of the input.
+        let step = answer.div_ceil(100);
+
+        let mut least = input.map(|_| None);
+        let draw = |least: &Grid<Option<usize>>| {
+            let mut image = Image::from_grid(input, |&heat| {
+                render::blend(render::BLACK, render::GREY, heat as f64 / 9.0)
+            });
+            for point in least.points() {
+                if let Some(heat_loss) = least[point] {
+                    let fraction = heat_loss as f64 / answer as f64;
+                    image.set(point, render::blend(render::BLUE, render::RED, fraction));
+                }
+            }
+            image
+        };
+
+        let mut result = Ok(());
+        let mut next_frame = step;
+        dijkstra(input, 0, 3, |point, heat_loss| {
+            if heat_loss >= next_frame && result.is_ok() {
+                result = frames.push(&draw(&least));
+                next_frame = heat_loss + step;
+            }
+
+            let tile: &mut Option<usize> = &mut least[point];
+            *tile = Some(tile.map_or(heat_loss, |least| least.min(heat_loss)));
+        });
+
+        result?;
+        frames.push(&draw(&least))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day17>();
+    }
+
+    #[test]
+    fn explains_unreachable_ends() {
+        use utils::Solution;
+
+        let grid = super::Day17::parse("12\n34").unwrap();
+        assert_eq!(super::Day17::part1(&grid), 6u32.into());
+        assert_eq!(
+            super::Day17::part2(&grid).failure(),
+            Some("no way for the crucible to reach the bottom right")
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use regex::Regex;
+
+use utils::{
+    error::parse_at,
+    geometry::{Direction, Vector},
+    math, Answer, ParseError, Solution,
+};
+
+/// The direction a step's letter digs in.
+fn direction(letter: &str) -> Option<Direction> {
+    match letter {
+        "R" => Some(Direction::East),
+        "L" => Some(Direction::West),
+        "D" => Some(Direction::South),
+        "U" => Some(Direction::North),
+        _ => None,
+    }
+}
+
+pub fn read_plan(text: &str) -> Result<DigPlan, ParseError> {
+    let regex = Regex::new(r"^([RLDU]) ([[:digit:]]+) \(\#([[:xdigit:]]{5})([0-3])\)$").unwrap();
+
+    let mut plan = DigPlan {
+        directions: Vec::new(),
+        hex_directions: Vec::new(),
+    };
+
+    for line in text.lines() {
+        let (_, [digit, number, hexstr, d]) = regex
+            .captures(line)
+            .ok_or_else(|| ParseError::at(text, line, "a step like `R 6 (#70c710)`"))?
+            .extract();
+
+        let length = parse_at(text, number, "a length")?;
+        let direction =
+            direction(digit).ok_or_else(|| ParseError::at(text, digit, "`R`, `L`, `D` or `U`"))?;
+        plan.directions.push((direction, length));
+
+        // The regex only lets through a digit from 0 to 3 and five hex digits.
+        let d_int = usize::from_str_radix(d, 16).unwrap();
+        let dir = [
+            Direction::East,
+            Direction::South,
+            Direction::West,
+            Direction::North,
+        ][d_int];
+        let hex = i64::from_str_radix(hexstr, 16).unwrap();
+        plan.hex_directions.push((dir, hex));
+    }
+
+    Ok(plan)
+}
+
+/// The dig plan read both ways: from the direction and length columns, and from the colour
+/// codes.
+pub struct DigPlan {
+    directions: Vec<(Direction, i64)>,
+    hex_directions: Vec<(Direction, i64)>,
+}
+
+/// The area dug out by following `dirs`, counting the trench itself.
+pub fn get_area(dirs: &[(Direction, i64)]) -> i64 {
+    let mut corner = Vector::default();
+    let mut corners = Vec::with_capacity(dirs.len());
+    for &(direction, l) in dirs {
+        corner += direction.vector() * l;
+        corners.push(corner);
+    }
+
+    (math::interior_points(&corners) + math::boundary_points(&corners)) as i64
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_plan(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        get_area(&input.directions).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        get_area(&input.hex_directions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn answers() {
+        utils::expected::check::<Day18>();
+    }
+
+    const INPUT: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn test_first() {
+        let result = get_area(&read_plan(INPUT).unwrap().directions);
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn test_second() {
+        let result = get_area(&read_plan(INPUT).unwrap().hex_directions);
+        assert_eq!(result, 952_408_144_115);
+    }
+
+    /// Digs the trench a square at a time, then counts every square the outside can't reach.
+    fn dig_and_flood(dirs: &[(char, i64)]) -> i64 {
+        let mut trench = HashSet::from([(0, 0)]);
+        let (mut y, mut x) = (0i64, 0i64);
+        for &(d, l) in dirs {
+            let (dy, dx) = match d {
+                'R' => (0, 1),
+                'L' => (0, -1),
+                'D' => (1, 0),
+                _ => (-1, 0),
+            };
+            for _ in 0..l {
+                (y, x) = (y + dy, x + dx);
+                trench.insert((y, x));
+            }
+        }
+
+        let min_y = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let max_y = trench.iter().map(|p| p.0).max().unwrap() + 1;
+        let min_x = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let max_x = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+        let mut outside = HashSet::from([(min_y, min_x)]);
+        let mut stack = vec![(min_y, min_x)];
+        while let Some((y, x)) = stack.pop() {
+            for next in [(y + 1, x), (y - 1, x), (y, x + 1), (y, x - 1)] {
+                let inside_box =
+                    (min_y..=max_y).contains(&next.0) && (min_x..=max_x).contains(&next.1);
+                if inside_box && !trench.contains(&next) && outside.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        (max_y - min_y + 1) * (max_x - min_x + 1) - outside.len() as i64
+    }
+
+    /// A trench around side by side blocks of different heights hanging from a straight top
+    /// edge, dug either way round and turned to face any way.
+    fn plan() -> impl Strategy<Value = Vec<(char, i64)>> {
+        let blocks = prop::collection::vec((1..6i64, 1..8i64), 1..6);
+        (blocks, prop::bool::ANY, 0..4usize).prop_map(|(blocks, reverse, turns)| {
+            let width = blocks.iter().map(|&(w, _)| w).sum();
+            let mut dirs = vec![('R', width)];
+            let mut height = 0;
+            for &(w, h) in blocks.iter().rev() {
+                match h - height {
+                    0 => {}
+                    dh if dh > 0 => dirs.push(('D', dh)),
+                    dh => dirs.push(('U', -dh)),
+                }
+                dirs.push(('L', w));
+                height = h;
+            }
+            dirs.push(('U', height));
+
+            if reverse {
+                dirs.reverse();
+                for (d, _) in &mut dirs {
+                    *d = match d {
+                        'R' => 'L',
+                        'L' => 'R',
+                        'D' => 'U',
+                        _ => 'D',
+                    };
+                }
+            }
+
+            let order = ['R', 'D', 'L', 'U'];
+            for (d, _) in &mut dirs {
+                let i = order.iter().position(|o| o == d).unwrap();
+                *d = order[(i + turns) % 4];
+            }
+            dirs
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn area_matches_digging_it_out(dirs in plan()) {
+            let steps = dirs
+                .iter()
+                .map(|&(d, l)| (direction(&d.to_string()).unwrap(), l))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(get_area(&steps), dig_and_flood(&dirs));
+        }
+    }
+}
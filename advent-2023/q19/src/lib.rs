@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+use utils::{error::parse_at, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    fn from_str(category: &str) -> Option<Self> {
+        match category {
+            "x" => Some(Category::X),
+            "m" => Some(Category::M),
+            "a" => Some(Category::A),
+            "s" => Some(Category::S),
+            _ => None,
+        }
+    }
+    fn to_str(category: &Category) -> String {
+        match category {
+            Category::X => "x",
+            Category::M => "m",
+            Category::A => "a",
+            Category::S => "s",
+        }
+        .to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Filter {
+    gtr: bool,
+    op1: Category,
+    op2: usize,
+    dst: String,
+}
+impl Filter {
+    fn new(gtr: bool, op1: Category, op2: usize, dst: &str) -> Self {
+        Filter {
+            gtr,
+            op1,
+            op2,
+            dst: dst.to_owned(),
+        }
+    }
+    /// Accpets or Rejects a Part based on the filter conditions
+    fn accept(&self, part: &Part) -> Option<&str> {
+        // Grabs relevant category of the part
+        let category = part.get_category(&self.op1);
+
+        // Checks if part's category fulfills the condition
+        let filter_result = if self.gtr {
+            category > self.op2
+        } else {
+            category < self.op2
+        };
+
+        // If bool is true wrap destination in `Some()` otherwise return `None`
+        filter_result.then_some(&self.dst)
+    }
+
+    /// Reads a filter like `a<2006:qkq` from `filter`, a slice of `input`.
+    fn from_str(input: &str, filter: &str, filter_reg: &Regex) -> Result<Self, ParseError> {
+        let (_, [op1, cmp, op2, dst]) = filter_reg
+            .captures(filter)
+            .ok_or_else(|| ParseError::at(input, filter, "a rule like `a<2006:qkq`"))?
+            .extract();
+
+        let category = Category::from_str(op1)
+            .ok_or_else(|| ParseError::at(input, op1, "`x`, `m`, `a` or `s`"))?;
+        let op2 = parse_at(input, op2, "a rating")?;
+
+        Ok(Filter::new(cmp == ">", category, op2, dst))
+    }
+
+    fn constrain(&self, aff: bool, dom: &mut [Vec<bool>]) {
+        let set = &mut dom["xmas".find(&Category::to_str(&self.op1)).unwrap()];
+        let r = if aff {
+            if self.gtr {
+                1..=self.op2
+            } else {
+                self.op2..=4000
+            }
+        } else if self.gtr {
+            self.op2 + 1..=4000
+        } else {
+            1..=self.op2 - 1
+        };
+
+        for i in r {
+            set[i] = false;
+        }
+    }
+}
+
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn new(x: usize, m: usize, a: usize, s: usize) -> Self {
+        Part { x, m, a, s }
+    }
+
+    /// Reads a part like `{x=787,m=2655,a=1222,s=2876}` from `part`, a line of `input`.
+    fn parse(input: &str, part: &str, reg: &Regex) -> Result<Self, ParseError> {
+        let (_, ratings) = reg
+            .captures(part)
+            .ok_or_else(|| ParseError::at(input, part, "a part like `{x=1,m=2,a=3,s=4}`"))?
+            .extract();
+
+        let [x, m, a, s] = ratings.map(|rating| parse_at(input, rating, "a rating"));
+
+        Ok(Part::new(x?, m?, a?, s?))
+    }
+
+    fn get_count(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+
+    /// Takes in a `Category` enum and returns the value of the part's category that the `Category`
+    /// enum asks for.
+    fn get_category(&self, category: &Category) -> usize {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    dest: String,
+    filters: Vec<Filter>,
+}
+
+impl Workflow {
+    fn new(dest: &str, filters: Vec<Filter>) -> Self {
+        Workflow {
+            dest: dest.to_owned(),
+            filters,
+        }
+    }
+
+    /// Iterates over all tests and if part fulfills one of them it returns the name of the new
+    /// `Workflow` the part should be past to. If it fulfiils no tests then the current
+    /// `Workflow`'s name is returned.
+    fn eval(&self, part: &Part) -> &str {
+        for test in &self.filters {
+            if let Some(x) = test.accept(part) {
+                return x;
+            }
+        }
+
+        &self.dest
+    }
+}
+
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl System {
+    fn new(workflows: HashMap<String, Workflow>) -> Self {
+        System { workflows }
+    }
+
+    /// Reads the workflows from `workflows`, a slice of `input`.
+    fn parse(input: &str, workflows: &str) -> Result<Self, ParseError> {
+        // Hellish regex that grabs name and needed features for building the `Workflow`
+        let line_reg =
+            Regex::new(r"([a-z]{2,3})\{((?:[xmas][<>][0-9]+:[[a-z][AR]]+,)+)([[a-z][AR]]+)}")
+                .unwrap();
+
+        let filter_reg = Regex::new(r"([xmas])([<>])([0-9]+):([[a-z][AR]]+)").unwrap();
+
+        let mut system = HashMap::new();
+
+        for line in workflows.lines() {
+            let line_capture = line_reg.captures(line).ok_or_else(|| {
+                ParseError::at(input, line, "a workflow like `px{a<2006:qkq,rfg}`")
+            })?;
+            // The name becomes the key into the hashmap
+            let name = line_capture.get(1).map_or("", |m| m.as_str());
+            // Tests from the workflow
+            let filters = line_capture.get(2).map_or("", |m| m.as_str());
+            let filters = &filters[..(filters.len() - 1)];
+            let filters = filters
+                .split(',')
+                .map(|filter| Filter::from_str(input, filter, &filter_reg))
+                .collect::<Result<Vec<Filter>, _>>()?;
+
+            // Destination for the workflow
+            let dest = line_capture.get(3).map_or("", |m| m.as_str());
+
+            let workflow = Workflow::new(dest, filters);
+
+            system.insert(name.to_owned(), workflow);
+        }
+
+        Ok(System::new(system))
+    }
+
+    fn process_part(&self, part: &Part) -> bool {
+        let mut key = "in";
+        loop {
+            let result = self.workflows[key].eval(part);
+            match result {
+                "A" => break true,
+                "R" => break false,
+                _ => key = result,
+            }
+        }
+    }
+
+    /// For every accept the number of unique part evaluations that can lead to that accept state
+    /// are counted. The total number is then return.
+    fn permute_possiblities(&self, key: &str, mut seq: Vec<(Filter, bool)>) -> usize {
+        match key {
+            "A" => {
+                let mut permutation: Vec<Vec<bool>> = (0..4)
+                    .map(|_| {
+                        let mut vec = vec![true; 4001];
+                        vec[0] = false;
+                        vec
+                    })
+                    .collect();
+
+                for (filter, is_accepted) in seq {
+                    filter.constrain(is_accepted, &mut permutation);
+                }
+
+                permutation
+                    .iter()
+                    .map(|v| v.iter().filter(|f| **f).count())
+                    .product()
+            }
+            "R" => 0,
+            _ => {
+                let workflow = &self.workflows[key];
+                let mut n = 0;
+
+                for filter in &workflow.filters {
+                    let mut seq_clone = seq.clone();
+                    seq_clone.push((filter.clone(), true));
+                    n += self.permute_possiblities(&filter.dst, seq_clone);
+                    seq.push((filter.clone(), false));
+                }
+                n += self.permute_possiblities(&workflow.dest, seq);
+                n
+            }
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<(System, Vec<Part>), ParseError> {
+    let (workflows, parts) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(input, input, "a blank line between the workflows and parts")
+    })?;
+
+    let part_reg =
+        Regex::new(r"^\{x=([0-9]{1,}),m=([0-9]{1,}),a=([0-9]{1,}),s=([0-9]{1,})}$").unwrap();
+
+    Ok((
+        System::parse(input, workflows)?,
+        parts
+            .lines()
+            .map(|part| Part::parse(input, part, &part_reg))
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+pub fn part_1(system: &System, parts: &[Part]) -> usize {
+    let mut total = 0;
+
+    for part in parts {
+        let is_accepted = system.process_part(part);
+
+        if is_accepted {
+            total += part.get_count();
+        }
+    }
+
+    total
+}
+
+pub fn part_2(system: &System) -> usize {
+    system.permute_possiblities("in", Vec::new())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input = (System, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(&input.0, &input.1).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(&input.0).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn answers() {
+        utils::expected::check::<Day19>();
+    }
+
+    #[test]
+    fn filter_accept_returns_properly() {
+        let filter = Filter::new(true, Category::S, 20, "Test");
+        let part = Part::new(0, 0, 0, 21);
+
+        assert_eq!(filter.accept(&part), Some("Test"));
+
+        let part = Part::new(0, 0, 0, 19);
+
+        assert_eq!(filter.accept(&part), None);
+
+        let filter = Filter::new(false, Category::S, 20, "Test");
+
+        assert_eq!(filter.accept(&part), Some("Test"));
+    }
+
+    #[test]
+    fn workflow_eval_returns_properly() {
+        let filter1 = Filter::new(true, Category::X, 20, "Test1");
+        let filter2 = Filter::new(false, Category::M, 20, "Test2");
+        let filter3 = Filter::new(true, Category::A, 20, "Test3");
+        let filter4 = Filter::new(false, Category::S, 20, "Test4");
+
+        let part1 = Part::new(21, 0, 0, 0);
+        let part2 = Part::new(0, 19, 0, 0);
+        let part3 = Part::new(0, 20, 21, 0);
+        let part4 = Part::new(0, 20, 0, 19);
+        let part5 = Part::new(0, 20, 0, 20);
+
+        let workflow = Workflow::new("Test", vec![filter1, filter2, filter3, filter4]);
+
+        assert_eq!(workflow.eval(&part1), "Test1");
+        assert_eq!(workflow.eval(&part2), "Test2");
+        assert_eq!(workflow.eval(&part3), "Test3");
+        assert_eq!(workflow.eval(&part4), "Test4");
+        assert_eq!(workflow.eval(&part5), "Test");
+    }
+
+    /// Counts the accepted parts by sending one part through the workflows for each range of
+    /// ratings that every rule treats the same, weighted by how many parts share its ranges.
+    fn count_by_sorting_parts(system: &System) -> usize {
+        let categories = [Category::X, Category::M, Category::A, Category::S];
+
+        // Each category's ratings split into ranges at every threshold a rule tests.
+        let ranges = categories.map(|category| {
+            let mut cuts = vec![1, 4001];
+            for filter in system.workflows.values().flat_map(|w| &w.filters) {
+                if Category::to_str(&filter.op1) == Category::to_str(&category) {
+                    cuts.push(if filter.gtr {
+                        filter.op2 + 1
+                    } else {
+                        filter.op2
+                    });
+                }
+            }
+            cuts.sort_unstable();
+            cuts.dedup();
+            cuts.windows(2)
+                .map(|w| (w[0], w[1] - w[0]))
+                .collect::<Vec<_>>()
+        });
+
+        let mut total = 0;
+        for &(x, x_count) in &ranges[0] {
+            for &(m, m_count) in &ranges[1] {
+                for &(a, a_count) in &ranges[2] {
+                    for &(s, s_count) in &ranges[3] {
+                        if system.process_part(&Part::new(x, m, a, s)) {
+                            total += x_count * m_count * a_count * s_count;
+                        }
+                    }
+                }
+            }
+        }
+        total
+    }
+
+    /// Up to four workflows starting with `in`, each sending parts only to the workflows
+    /// after it, so every part ends up accepted or rejected.
+    fn system() -> impl Strategy<Value = System> {
+        let filter = (prop::bool::ANY, 0..4usize, 1..=4000usize, 0..8usize);
+        let workflow = (prop::collection::vec(filter, 1..4), 0..8usize);
+        prop::collection::vec(workflow, 1..5).prop_map(|workflows| {
+            let names = ["in", "ab", "cd", "ef"];
+            let count = workflows.len();
+            // Picks one of the workflows after `i`, or `A` or `R`.
+            let dest = |i: usize, pick: usize| {
+                let later = count - i - 1;
+                match pick % (later + 2) {
+                    0 => "A",
+                    1 => "R",
+                    n => names[i + n - 1],
+                }
+            };
+
+            let workflows = workflows
+                .into_iter()
+                .enumerate()
+                .map(|(i, (filters, default))| {
+                    let categories = [Category::X, Category::M, Category::A, Category::S];
+                    let filters = filters
+                        .into_iter()
+                        .map(|(gtr, category, rating, pick)| {
+                            Filter::new(gtr, categories[category].clone(), rating, dest(i, pick))
+                        })
+                        .collect();
+                    (
+                        names[i].to_string(),
+                        Workflow::new(dest(i, default), filters),
+                    )
+                })
+                .collect();
+            System::new(workflows)
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn combinations_match_sorting_parts(system in system()) {
+            prop_assert_eq!(
+                system.permute_possiblities("in", Vec::new()),
+                count_by_sorting_parts(&system)
+            );
+        }
+    }
+}
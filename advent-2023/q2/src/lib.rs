@@ -0,0 +1,121 @@
+use utils::{
+    scan::Scanner,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+/// The most cubes of each colour shown at once during a game.
+#[derive(Default)]
+pub struct Cubes {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub fn parse_game(line: &str) -> Result<Cubes, ParseError> {
+    let mut cubes = Cubes::default();
+
+    let mut scan = Scanner::new(line);
+    scan.skip_past(b':');
+
+    while let Some(value) = scan
+        .next_number::<u32>()
+        .map_err(|err| err.in_input(line, line))?
+    {
+        match scan.next_ident() {
+            Some("red") => cubes.red = cubes.red.max(value),
+            Some("green") => cubes.green = cubes.green.max(value),
+            Some("blue") => cubes.blue = cubes.blue.max(value),
+            Some(other) => return Err(ParseError::at(line, other, "`red`, `green` or `blue`")),
+            None => return Err(ParseError::at_end(line, line, "a colour")),
+        }
+    }
+
+    Ok(cubes)
+}
+
+/// Whether the bag could have held the game's cubes: 12 red, 13 green and 14 blue.
+pub fn possible(cubes: &Cubes) -> bool {
+    cubes.red <= 12 && cubes.green <= 13 && cubes.blue <= 14
+}
+
+pub fn part_1(games: &[Cubes]) -> usize {
+    games
+        .iter()
+        .enumerate()
+        .filter(|(_, cubes)| possible(cubes))
+        .map(|(id, _)| id + 1)
+        .sum::<usize>()
+}
+
+pub fn part_2(games: &[Cubes]) -> u32 {
+    games
+        .iter()
+        .map(|cubes| cubes.red * cubes.green * cubes.blue)
+        .sum::<u32>()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Cubes>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_game)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// The games read so far, with the sums of the possible games' IDs and of every game's
+/// power.
+#[derive(Default)]
+pub struct Games {
+    count: usize,
+    possible: usize,
+    power: u32,
+}
+
+impl Stream for Day2 {
+    type State = Games;
+
+    fn read_line(games: &mut Games, line: &str) -> Result<(), ParseError> {
+        let game = parse_game(line)?;
+        games.count += 1;
+        if possible(&game) {
+            games.possible += games.count;
+        }
+        games.power += part_2(&[game]);
+        Ok(())
+    }
+
+    fn answer1(games: &Games) -> Answer {
+        games.possible.into()
+    }
+
+    fn answer2(games: &Games) -> Answer {
+        games.power.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day2>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day2>();
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    fmt,
+};
+
+use regex::Regex;
+use utils::{cycle, simulate::Simulation, Answer, ParseError, Solution};
+
+#[derive(PartialEq, Clone)]
+enum Pulse {
+    Low,
+    High,
+}
+
+impl fmt::Display for Pulse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Pulse::Low => write!(f, "low"),
+            Pulse::High => write!(f, "high"),
+        }
+    }
+}
+
+type Identifier = String;
+
+#[derive(Clone)]
+enum ModuleRole {
+    Conjunction { recent: HashMap<Identifier, Pulse> },
+    FlipFlop { recent: Pulse, is_on: bool },
+    Broadcast { recent: Pulse },
+    Button,
+}
+
+#[derive(Clone)]
+pub struct Module {
+    identifier: Identifier,
+    role: ModuleRole,
+    receivers: Vec<String>,
+    n_high: u32,
+    n_low: u32,
+}
+
+impl Module {
+    fn new(identifier: &str, role: ModuleRole, receivers: Vec<String>) -> Self {
+        Self {
+            identifier: identifier.into(),
+            receivers,
+            role,
+            n_high: 0,
+            n_low: 0,
+        }
+    }
+    fn new_button(identifier: &str, receiver: &str) -> Self {
+        Self::new(identifier, ModuleRole::Button, vec![receiver.into()])
+    }
+    fn new_conjunction(identifier: &str, targets: Vec<Identifier>) -> Self {
+        Self::new(
+            identifier,
+            ModuleRole::Conjunction {
+                recent: HashMap::new(),
+            },
+            targets,
+        )
+    }
+    fn new_flip_flop(identifier: &str, targets: Vec<Identifier>) -> Self {
+        Self::new(
+            identifier,
+            ModuleRole::FlipFlop {
+                recent: Pulse::Low,
+                is_on: false,
+            },
+            targets,
+        )
+    }
+    fn new_broadcast(identifier: &str, targets: Vec<Identifier>) -> Self {
+        Self::new(
+            identifier,
+            ModuleRole::Broadcast { recent: Pulse::Low },
+            targets,
+        )
+    }
+
+    fn counts(&self) -> (u32, u32) {
+        (self.n_high, self.n_low)
+    }
+
+    fn receive(&mut self, source: &str, pulse: Pulse) {
+        match self.role {
+            ModuleRole::Conjunction { ref mut recent } => {
+                if let Some(input) = recent.get_mut(source) {
+                    *input = pulse;
+                }
+            }
+            ModuleRole::FlipFlop { ref mut recent, .. } => {
+                *recent = pulse;
+            }
+            ModuleRole::Broadcast { ref mut recent } => {
+                *recent = pulse;
+            }
+            ModuleRole::Button => { /* Not a receiver */ }
+        }
+    }
+
+    fn send(&mut self, mediator: &mut Mediator) {
+        match self.role {
+            ModuleRole::Conjunction { ref recent } => {
+                let pulse = if recent.values().all(|p| *p == Pulse::High) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                };
+
+                self.count_and_target_all(mediator, pulse);
+            }
+            ModuleRole::FlipFlop {
+                ref recent,
+                ref mut is_on,
+            } => {
+                if *recent == Pulse::Low {
+                    *is_on = !*is_on;
+                    let pulse = if *is_on { Pulse::High } else { Pulse::Low };
+
+                    self.count_and_target_all(mediator, pulse);
+                }
+            }
+            ModuleRole::Broadcast { ref recent } => {
+                self.count_and_target_all(mediator, recent.clone());
+            }
+            ModuleRole::Button => {
+                self.count_and_target_all(mediator, Pulse::Low);
+            }
+        }
+    }
+
+    fn count_and_target_all(&mut self, mediator: &mut Mediator, pulse: Pulse) {
+        if pulse == Pulse::High {
+            self.n_high += 1;
+        } else {
+            self.n_low += 1;
+        }
+
+        for target in &self.receivers {
+            mediator.send(&self.identifier, target, pulse.clone());
+        }
+    }
+}
+
+struct Mediator {
+    queue: VecDeque<(Identifier, Identifier, Pulse)>,
+    n_high: u32,
+    n_low: u32,
+}
+
+impl Mediator {
+    fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            n_high: 0,
+            n_low: 0,
+        }
+    }
+    fn get_pulse_counts(&self) -> u32 {
+        self.n_high * self.n_low
+    }
+
+    fn send(&mut self, source: &str, target: &str, pulse: Pulse) {
+        if pulse == Pulse::High {
+            self.n_high += 1;
+        } else {
+            self.n_low += 1;
+        }
+
+        self.queue.push_back((source.into(), target.into(), pulse));
+    }
+    /// Delivers the pulse at the front of the queue, returning whether there was one.
+    fn deliver_next(&mut self, modules: &mut HashMap<Identifier, Module>) -> bool {
+        let Some((source, target, pulse)) = self.queue.pop_front() else {
+            return false;
+        };
+        if let Some(module) = modules.get_mut(&target) {
+            module.receive(&source, pulse);
+            module.send(self);
+        }
+        true
+    }
+
+    fn loop_until_done(&mut self, modules: &mut HashMap<Identifier, Module>) {
+        while self.deliver_next(modules) {}
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let line_reg = Regex::new(r"^([%&]?)(\w+) +-> +(.*)$").unwrap();
+    let targets_reg = Regex::new(r"(\w+)").unwrap();
+    let mut modules = HashMap::<String, Module>::new();
+
+    for line in input.lines() {
+        let caps = line_reg
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "a module like `%a -> b, c`"))?;
+        let (_, [pfx, name, targets]) = caps.extract();
+        let targets = targets_reg
+            .captures_iter(targets)
+            .map(|c| c[1].into())
+            .collect::<Vec<String>>();
+
+        let module = {
+            match pfx {
+                "&" => Module::new_conjunction(name, targets),
+                "%" => Module::new_flip_flop(name, targets),
+                _ => Module::new_broadcast(name, targets),
+            }
+        };
+        modules.insert(name.into(), module);
+    }
+
+    for (sender_id, module) in modules.clone().iter() {
+        for receiver_id in &module.receivers {
+            if let Some(receiver) = modules.get_mut(receiver_id) {
+                if let ModuleRole::Conjunction { ref mut recent } = receiver.role {
+                    recent.insert(sender_id.into(), Pulse::Low);
+                }
+            }
+        }
+    }
+    Ok(modules)
+}
+
+pub fn part_1(modules: &HashMap<String, Module>) -> u32 {
+    let mut modules = modules.clone();
+    let mut mediator = Mediator::new();
+    let mut button = Module::new_button("button", "broadcaster");
+
+    for _ in 0..1000 {
+        button.send(&mut mediator);
+        mediator.loop_until_done(&mut modules);
+    }
+
+    mediator.get_pulse_counts()
+}
+
+/// `target` and every module that can send pulses its way, directly or not, by name.
+/// Nothing else can change what `target` does.
+fn upstream<'a>(modules: &'a HashMap<String, Module>, target: &'a str) -> Vec<&'a str> {
+    let mut found = BTreeSet::from([target]);
+    let mut stack = vec![target];
+    while let Some(name) = stack.pop() {
+        for (sender, module) in modules {
+            if module.receivers.iter().any(|receiver| receiver == name)
+                && found.insert(sender.as_str())
+            {
+                stack.push(sender.as_str());
+            }
+        }
+    }
+    found.into_iter().collect()
+}
+
+/// What `names` remember between presses: whether each flip-flop is on, and whether each
+/// conjunction last heard a high pulse from each of its inputs.
+fn memory(modules: &HashMap<String, Module>, names: &[&str]) -> Vec<bool> {
+    let mut memory = Vec::new();
+    for name in names {
+        match &modules[*name].role {
+            ModuleRole::FlipFlop { is_on, .. } => memory.push(*is_on),
+            ModuleRole::Conjunction { recent } => {
+                let mut inputs = recent.iter().collect::<Vec<_>>();
+                inputs.sort_unstable_by_key(|&(input, _)| input);
+                memory.extend(inputs.into_iter().map(|(_, pulse)| *pulse == Pulse::High));
+            }
+            ModuleRole::Broadcast { .. } | ModuleRole::Button => (),
+        }
+    }
+    memory
+}
+
+/// Presses until a single low pulse is sent to `rx`, or says why that never happens.
+///
+/// `rx` is fed by one conjunction, which sends it a low pulse once every one of its inputs
+/// has sent it a high pulse during the same press. What each input does only depends on
+/// the modules upstream of it, so it fires on a cycle of presses found from when those
+/// modules are back the way they were, and the answer is where the cycles line up.
+pub fn part_2(modules: &HashMap<String, Module>) -> Result<u64, String> {
+    let mut feeders = modules
+        .values()
+        .filter(|module| module.receivers.iter().any(|receiver| receiver == "rx"));
+    let (Some(feeder), None) = (feeders.next(), feeders.next()) else {
+        return Err("no single module sends pulses to `rx`".to_string());
+    };
+    let ModuleRole::Conjunction { recent } = &feeder.role else {
+        return Err("the module sending pulses to `rx` is not a conjunction".to_string());
+    };
+
+    let mut inputs = Vec::new();
+    for input in recent.keys() {
+        let names = upstream(modules, input);
+        let mut mediator = Mediator::new();
+        let mut button = Module::new_button("button", "broadcaster");
+        // The presses, counting from 0, during which `input` sends a high pulse.
+        let (mut pressed, mut fired) = (0, Vec::new());
+
+        let found = cycle::find_by_key(
+            &mut modules.clone(),
+            |modules| {
+                let before = modules[input].counts().0;
+                button.send(&mut mediator);
+                mediator.loop_until_done(modules);
+                if modules[input].counts().0 > before {
+                    fired.push(pressed);
+                }
+                pressed += 1;
+            },
+            |modules| memory(modules, &names),
+        );
+        if fired.is_empty() {
+            return Err(format!("`{input}` never sends a high pulse"));
+        }
+        inputs.push((found, fired));
+    }
+
+    cycle::first_together(&inputs)
+        .map(|presses| presses as u64 + 1)
+        .ok_or_else(|| "the conjunction's inputs never fire on the same press".to_string())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input = HashMap<String, Module>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match part_2(input) {
+            Ok(presses) => presses.into(),
+            Err(why) => Answer::failed(why),
+        }
+    }
+}
+
+/// The modules part way through part 1's presses of the button.
+pub struct Pressing {
+    modules: HashMap<Identifier, Module>,
+    mediator: Mediator,
+    button: Module,
+    presses: u32,
+}
+
+impl Simulation for Day20 {
+    type State = Pressing;
+
+    fn start(input: &Self::Input) -> Pressing {
+        Pressing {
+            modules: input.clone(),
+            mediator: Mediator::new(),
+            button: Module::new_button("button", "broadcaster"),
+            presses: 0,
+        }
+    }
+
+    /// Delivers one pulse, pressing the button once they have all been delivered.
+    fn step(_: &Self::Input, state: &mut Pressing) {
+        if !state.mediator.deliver_next(&mut state.modules) {
+            state.button.send(&mut state.mediator);
+            state.presses += 1;
+        }
+    }
+
+    /// Lists the pulses waiting to be delivered, then how each flip-flop and conjunction
+    /// was left.
+    fn render(_: &Self::Input, state: &Pressing) -> String {
+        let mediator = &state.mediator;
+        let mut lines = vec![format!(
+            "Presses: {}, pulses sent: {} low, {} high",
+            state.presses, mediator.n_low, mediator.n_high
+        )];
+
+        for (source, target, pulse) in &mediator.queue {
+            lines.push(format!("  {} -{}-> {}", source, pulse, target));
+        }
+
+        let mut modules = state.modules.values().collect::<Vec<_>>();
+        modules.sort_by_key(|module| &module.identifier);
+        for module in modules {
+            match &module.role {
+                ModuleRole::FlipFlop { is_on, .. } => {
+                    let on = if *is_on { "on" } else { "off" };
+                    lines.push(format!("%{} {}", module.identifier, on));
+                }
+                ModuleRole::Conjunction { recent } => {
+                    let mut inputs = recent.iter().collect::<Vec<_>>();
+                    inputs.sort_by_key(|(input, _)| *input);
+                    let inputs = inputs
+                        .into_iter()
+                        .map(|(input, pulse)| format!("{}={}", input, pulse))
+                        .collect::<Vec<_>>();
+                    lines.push(format!("&{} {}", module.identifier, inputs.join(", ")));
+                }
+                ModuleRole::Broadcast { .. } | ModuleRole::Button => {}
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Done after the 1000th press, once all its pulses are delivered.
+    fn done(_: &Self::Input, state: &Pressing) -> bool {
+        state.presses == 1000 && state.mediator.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day20>();
+    }
+
+    #[test]
+    fn explains_unanswerable_part_2() {
+        for (input, why) in [
+            ("broadcaster -> a", "no single module sends pulses to `rx`"),
+            (
+                "broadcaster -> a\n%a -> rx",
+                "the module sending pulses to `rx` is not a conjunction",
+            ),
+            (
+                "broadcaster -> b\n&b -> rx",
+                "`broadcaster` never sends a high pulse",
+            ),
+        ] {
+            let modules = super::parse_input(input).unwrap();
+            assert_eq!(super::part_2(&modules), Err(why.to_string()), "{input}");
+        }
+
+        // `a` turns on, sending a high pulse, every other press.
+        let modules = super::parse_input("broadcaster -> a\n%a -> b\n&b -> rx").unwrap();
+        assert_eq!(super::part_2(&modules), Ok(1));
+    }
+}
@@ -0,0 +1,260 @@
+use core::fmt;
+use std::io;
+
+use utils::{
+    geometry::{Direction, Vector},
+    graph,
+    grid::{Grid, Point},
+    math,
+    render::{self, Frames, Image, Render},
+    simulate::Simulation,
+    Answer, ParseError, Solution,
+};
+
+// General structure for solution:
+// point x is a '.' and a 'O' or 'S' is next to it
+// mark that spot on a fresh graph as an 'O'.
+// The fresh graph is created at the start of every loop.
+// The fresh graph is the last iteration with all 'O' and 'S' removed.
+// Iterate 64 times for answer.
+//
+#[allow(clippy::enum_variant_names)]
+#[derive(PartialEq, Clone)]
+enum Step {
+    Start,
+    Empty,
+    Rock,
+    Step,
+}
+
+impl Step {
+    fn map(character: char) -> Option<Self> {
+        match character {
+            'S' => Some(Step::Start),
+            '.' => Some(Step::Empty),
+            '#' => Some(Step::Rock),
+            'O' => Some(Step::Step),
+            _ => None,
+        }
+    }
+
+    fn check_move(step: &Step) -> bool {
+        *step == Step::Start || *step == Step::Step
+    }
+
+    fn check_move_p2(step: &Step) -> bool {
+        *step != Step::Rock
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Start => write!(f, "S"),
+            Step::Empty => write!(f, "."),
+            Step::Rock => write!(f, "#"),
+            Step::Step => write!(f, "O"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Plot {
+    plot: Grid<Step>,
+}
+
+impl Plot {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let plot =
+            Grid::parse(input, Step::map).map_err(|err| err.expecting("`S`, `.`, `#` or `O`"))?;
+        if !plot.iter().any(|step| *step == Step::Start) {
+            return Err(ParseError::at_end(input, input, "an `S` marking the start"));
+        }
+
+        Ok(Plot { plot })
+    }
+
+    fn create_fresh_plot(&self) -> Plot {
+        let plot = self.plot.map(|step| {
+            if *step == Step::Step || *step == Step::Start {
+                Step::Empty
+            } else {
+                step.clone()
+            }
+        });
+        Plot { plot }
+    }
+
+    fn count_steps(&self) -> u32 {
+        self.plot.iter().filter(|step| **step == Step::Step).count() as u32
+    }
+}
+
+impl fmt::Display for Plot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\n{}", self.plot)
+    }
+}
+
+/// Every plot reachable in one more step from the plots reached in `plot`.
+pub fn step(plot: &Plot) -> Plot {
+    // create fresh grid
+    let mut fresh_plot = plot.create_fresh_plot();
+
+    // iterate over fresh grid and check last iteration for adjacent steps
+    for point in plot.plot.points() {
+        if fresh_plot.plot[point] == Step::Empty
+            && plot
+                .plot
+                .neighbours(point)
+                .any(|next| Step::check_move(&plot.plot[next]))
+        {
+            fresh_plot.plot[point] = Step::Step;
+        }
+    }
+
+    fresh_plot
+}
+
+pub fn part_1(plot: &Plot) -> u32 {
+    let mut plot = plot.clone();
+
+    for _ in 0..64 {
+        // fresh grid replaces last grid iteration repeats
+        plot = step(&plot);
+    }
+
+    // Count steps in plot and return
+    plot.count_steps()
+}
+
+/// How many plots can be reached in exactly `steps` steps from the start, for each of
+/// `steps`, on the map repeated forever in every direction.
+fn reachable_on_repeated_map<const N: usize>(plot: &Plot, steps: [usize; N]) -> [usize; N] {
+    let (width, height) = (plot.plot.width() as i64, plot.plot.height() as i64);
+    let open = |point: Vector| {
+        let tile = Point::new(
+            point.x.rem_euclid(width) as usize,
+            point.y.rem_euclid(height) as usize,
+        );
+        Step::check_move_p2(&plot.plot[tile])
+    };
+    let plots = |&point: &Vector| {
+        Direction::ALL
+            .map(|direction| point + direction.vector())
+            .into_iter()
+            .filter(move |&next| open(next))
+    };
+
+    let start = plot
+        .plot
+        .points()
+        .find(|&point| plot.plot[point] == Step::Start);
+    let most = steps.into_iter().max().unwrap_or(0);
+    let distances = graph::bfs(Vector::from(start.unwrap()), plots)
+        .map(|(_, distance)| distance)
+        .take_while(|&distance| distance <= most)
+        .collect::<Vec<_>>();
+
+    // A plot reached early can be reached again two steps later by stepping away and back.
+    steps.map(|steps| {
+        distances
+            .iter()
+            .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    })
+}
+
+pub fn part_2(plot: &Plot) -> usize {
+    const STEPS: usize = 26501365;
+
+    // The start is in the middle of a square map with clear paths straight out to its
+    // edges, so each time the steps cross one more whole copy of the map, the plots
+    // reached grow like a quadratic in the number of copies crossed.
+    let size = plot.plot.width();
+    let offset = STEPS % size;
+    let counts = reachable_on_repeated_map(plot, [0, 1, 2].map(|copies| offset + copies * size));
+
+    let counts = counts.map(|count| count as i64);
+    math::extrapolate(&counts, (STEPS / size) as i64) as usize
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Input = Plot;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Plot::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+impl Render for Day21 {
+    /// Draws the plots the elf could be on after each of the 64 steps of part 1.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        let draw = |plot: &Plot| {
+            Image::from_grid(&plot.plot, |step| match step {
+                Step::Start => render::RED,
+                Step::Empty => render::BLACK,
+                Step::Rock => render::GREY,
+                Step::Step => render::GREEN,
+            })
+        };
+
+        let mut plot = input.clone();
+        frames.push(&draw(&plot))?;
+        for _ in 0..64 {
+            plot = step(&plot);
+            frames.push(&draw(&plot))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Simulation for Day21 {
+    /// The plots reached so far, and in how many steps.
+    type State = (Plot, usize);
+
+    fn start(input: &Self::Input) -> (Plot, usize) {
+        (input.clone(), 0)
+    }
+
+    fn step(_: &Self::Input, (plot, steps): &mut (Plot, usize)) {
+        *plot = step(plot);
+        *steps += 1;
+    }
+
+    fn render(_: &Self::Input, (plot, steps): &(Plot, usize)) -> String {
+        format!(
+            "Steps: {}, plots reached: {}{}",
+            steps,
+            plot.count_steps(),
+            plot
+        )
+    }
+
+    /// Done after the 64 steps of part 1.
+    fn done(_: &Self::Input, (_, steps): &(Plot, usize)) -> bool {
+        *steps == 64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day21>();
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::{HashSet, VecDeque};
+
+use utils::{
+    error::parse_at,
+    geometry::{Bounds, Point3},
+    simulate::Simulation,
+    Answer, ParseError, Solution,
+};
+
+type BrickID = usize;
+const NO_BRICK: BrickID = usize::MAX;
+
+#[derive(Clone)]
+pub struct Brick {
+    cubes: Bounds<Point3>,
+    id: usize,
+    below: Vec<BrickID>,
+    above: Vec<BrickID>,
+}
+
+impl Brick {
+    fn new(end1: Point3, end2: Point3) -> Self {
+        Self {
+            cubes: Bounds::new(end1, end2),
+            id: 0,
+            below: Vec::with_capacity(10),
+            above: Vec::with_capacity(10),
+        }
+    }
+
+    fn set_id(&mut self, id: usize) {
+        self.id = id;
+    }
+
+    fn lo_z(&self) -> usize {
+        self.cubes.min.z
+    }
+
+    fn hi_z(&self) -> usize {
+        self.cubes.max.z
+    }
+
+    fn can_safely_remove(&self, bricks: &[Brick]) -> bool {
+        for &b in &self.above {
+            if bricks[b].below.len() == 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn land(&mut self, platform: &mut [[BrickID; 10]; 10], bricks: &mut [Brick]) {
+        let mut below = HashSet::new();
+        let mut high = 0;
+
+        for x in self.cubes.min.x..=self.cubes.max.x {
+            for y in self.cubes.min.y..=self.cubes.max.y {
+                if platform[x][y] != NO_BRICK {
+                    high = high.max(bricks[platform[x][y]].hi_z());
+                    below.insert(platform[x][y]);
+                }
+                platform[x][y] = self.id;
+            }
+        }
+
+        for id in below {
+            if bricks[id].hi_z() == high {
+                self.below.push(id);
+                bricks[id].above.push(self.id);
+            }
+        }
+
+        let height = self.hi_z() - self.lo_z();
+        self.cubes.min.z = high + 1;
+        self.cubes.max.z = high + 1 + height;
+    }
+
+    fn parse(input: &str) -> Result<Vec<Brick>, ParseError> {
+        // Parse input into bricks
+        let mut bricks = Vec::new();
+
+        for line in input.lines() {
+            let coords = line.split(&[',', '~']).collect::<Vec<_>>();
+            if coords.len() != 6 {
+                return Err(ParseError::at(input, line, "a brick like `1,0,1~1,2,1`"));
+            }
+
+            let mut ends = [Point3::default(); 2];
+            for (end, coords) in ends.iter_mut().zip(coords.chunks(3)) {
+                let [x, y, z] = [0, 1, 2].map(|i| parse_at(input, coords[i], "a coordinate"));
+                *end = Point3::new(x?, y?, z?);
+                // The height map used to drop the bricks is 10 by 10.
+                for (coord, value) in [(coords[0], end.x), (coords[1], end.y)] {
+                    if value >= 10 {
+                        return Err(ParseError::at(input, coord, "an x or y below 10"));
+                    }
+                }
+            }
+            bricks.push(Brick::new(ends[0], ends[1]));
+        }
+
+        // Assign ids to each bricks
+        bricks.sort_by_key(|b| b.lo_z());
+        bricks.iter_mut().enumerate().for_each(|(i, b)| b.set_id(i));
+
+        Ok(bricks)
+    }
+
+    fn place_bricks(bricks: Vec<Brick>) -> Vec<Brick> {
+        let mut settling = Settling::new(bricks);
+        while !settling.done() {
+            settling.land_next();
+        }
+
+        settling.bricks
+    }
+
+    fn will_fall(&self, is_falling: &[bool]) -> bool {
+        self.below.iter().all(|&b| is_falling[b])
+    }
+}
+
+/// Bricks dropping onto the ground one at a time, lowest first.
+pub struct Settling {
+    bricks: Vec<Brick>,
+    /// The brick on top of each column of the height map.
+    platform: [[BrickID; 10]; 10],
+    landed: usize,
+}
+
+impl Settling {
+    fn new(bricks: Vec<Brick>) -> Self {
+        Self {
+            bricks,
+            platform: [[NO_BRICK; 10]; 10],
+            landed: 0,
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.landed == self.bricks.len()
+    }
+
+    /// Drops the next brick onto the ones already landed.
+    fn land_next(&mut self) {
+        let mut brick = self.bricks[self.landed].clone();
+        brick.land(&mut self.platform, &mut self.bricks);
+        self.bricks[self.landed] = brick;
+        self.landed += 1;
+    }
+
+    /// The bricks seen from the side, looking along the y axis if `along_y` and the x axis
+    /// otherwise, as in the puzzle: each brick is a letter, or `?` where several overlap.
+    fn side_view(&self, along_y: bool) -> Vec<String> {
+        let across = |point: Point3| if along_y { point.x } else { point.y };
+        let width = self
+            .bricks
+            .iter()
+            .map(|brick| across(brick.cubes.max) + 1)
+            .max()
+            .unwrap_or(0);
+        let top = self.bricks.iter().map(Brick::hi_z).max().unwrap_or(0);
+
+        let mut lines = (1..=top)
+            .rev()
+            .map(|z| {
+                (0..width)
+                    .map(|a| {
+                        let mut seen = self.bricks.iter().filter(|brick| {
+                            (across(brick.cubes.min)..=across(brick.cubes.max)).contains(&a)
+                                && (brick.lo_z()..=brick.hi_z()).contains(&z)
+                        });
+                        match (seen.next(), seen.next()) {
+                            (None, _) => '.',
+                            (Some(brick), None) => (b'A' + (brick.id % 26) as u8) as char,
+                            (Some(_), Some(_)) => '?',
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        lines.push("-".repeat(width));
+        lines
+    }
+}
+
+pub fn part_1(bricks: &[Brick]) -> usize {
+    // Place the bricks
+    let bricks = Brick::place_bricks(bricks.to_vec());
+
+    // Remove safe bricks
+    let mut count = 0;
+    for b in &bricks {
+        if b.can_safely_remove(&bricks) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub fn part_2(bricks: &[Brick]) -> usize {
+    // Place the bricks
+    let bricks = Brick::place_bricks(bricks.to_vec());
+
+    let mut count = 0;
+    let mut is_falling = vec![false; bricks.len()];
+    let mut queue = VecDeque::new();
+
+    for b in &bricks {
+        is_falling[b.id] = true;
+        queue.push_back(b.id);
+
+        while let Some(b) = queue.pop_front() {
+            for b in &bricks[b].above {
+                if !is_falling[*b] && bricks[*b].will_fall(&is_falling) {
+                    is_falling[*b] = true;
+                    queue.push_back(*b);
+                    count += 1;
+                }
+            }
+        }
+        is_falling.fill(false);
+    }
+
+    count
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+
+    type Input = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Brick::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+impl Simulation for Day22 {
+    type State = Settling;
+
+    fn start(input: &Self::Input) -> Settling {
+        Settling::new(input.clone())
+    }
+
+    fn step(_: &Self::Input, settling: &mut Settling) {
+        settling.land_next();
+    }
+
+    /// Draws the bricks from the front and from the side, next to each other.
+    fn render(_: &Self::Input, settling: &Settling) -> String {
+        let (front, side) = (settling.side_view(true), settling.side_view(false));
+        let width = front.first().map_or(0, String::len);
+        let header = format!("Landed: {} of {}", settling.landed, settling.bricks.len());
+        let views = front
+            .iter()
+            .zip(&side)
+            .map(|(front, side)| format!("{:width$}   {}", front, side, width = width));
+
+        std::iter::once(header)
+            .chain(views)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn done(_: &Self::Input, settling: &Settling) -> bool {
+        settling.done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day22>();
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::VecDeque, io};
+
+use utils::{
+    graph,
+    grid::{self, Direction, Point},
+    render::{self, Frames, Image, Render},
+    Answer, ParseError, Solution,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Land {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+pub struct Grid(grid::Grid<Land>);
+
+impl Grid {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = grid::Grid::parse(input, |c| match c {
+            '.' => Some(Land::Path),
+            '#' => Some(Land::Forest),
+            '^' => Some(Land::Slope(Direction::North)),
+            '<' => Some(Land::Slope(Direction::West)),
+            '>' => Some(Land::Slope(Direction::East)),
+            'v' => Some(Land::Slope(Direction::South)),
+            _ => None,
+        })
+        .map_err(|err| err.expecting("`.`, `#`, `^`, `<`, `>` or `v`"))?;
+
+        Ok(Grid(grid))
+    }
+
+    /// The tiles a hike can step on next from `point`. Slopes can only be left downhill
+    /// when they are `slippery`.
+    fn trails(&self, point: Point, slippery: bool) -> impl Iterator<Item = Point> + '_ {
+        let land = self.0[point];
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| match land {
+                Land::Slope(downhill) if slippery => direction == downhill,
+                _ => true,
+            })
+            .filter_map(move |direction| self.0.go(point, direction))
+            .filter(|&next| self.0[next] != Land::Forest)
+    }
+
+    fn start(&self) -> Point {
+        Point::new(1, 0)
+    }
+
+    fn end(&self) -> Point {
+        Point::new(self.0.width() - 2, self.0.height() - 1)
+    }
+
+    /// Every hike from the start to the end that goes down slopes only the way they point
+    /// and never steps on a tile twice, in the order they are found.
+    fn hikes(&self) -> Vec<Vec<Point>> {
+        let mut queue = VecDeque::from([vec![self.start()]]);
+        let mut hikes = vec![];
+
+        while let Some(hike) = queue.pop_front() {
+            let last = *hike.last().unwrap();
+            if last == self.end() {
+                hikes.push(hike);
+                continue;
+            }
+            for next in self.trails(last, true) {
+                if !hike.contains(&next) {
+                    let mut hike = hike.clone();
+                    hike.push(next);
+                    queue.push_back(hike);
+                }
+            }
+        }
+
+        hikes
+    }
+
+    /// The most steps a hike from the start to the end can take without stepping on a tile
+    /// twice, found over the junctions between trails, if the end can be reached at all.
+    fn longest_hike(&self, slippery: bool) -> Option<usize> {
+        let trails = graph::compress(self.start(), |&point| self.trails(point, slippery));
+        trails
+            .index(&self.end())
+            .and_then(|end| trails.longest_path(0, end))
+    }
+}
+
+pub fn part_1(grid: &Grid) -> Option<usize> {
+    grid.longest_hike(true)
+}
+
+pub fn part_2(grid: &Grid) -> Option<usize> {
+    grid.longest_hike(false)
+}
+
+/// The answer to a part, or why there is none.
+fn hike_answer(steps: Option<usize>) -> Answer {
+    match steps {
+        Some(steps) => steps.into(),
+        None => Answer::failed("no hike from the start to the end"),
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        hike_answer(part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        hike_answer(part_2(input))
+    }
+}
+
+impl Render for Day23 {
+    /// Draws each hike part 1 finds down the slopes in turn, shortest first, over the trails
+    /// walked so far, ending on the longest.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()> {
+        let hikes = input.hikes();
+
+        let mut walked = Image::from_grid(&input.0, |land| match land {
+            Land::Path => render::BLACK,
+            Land::Forest => render::GREY,
+            Land::Slope(_) => render::BLUE,
+        });
+        for hike in &hikes {
+            let mut image = walked.clone();
+            image.overlay(hike.iter().copied(), render::YELLOW);
+            frames.push(&image)?;
+            walked.overlay(hike.iter().copied(), render::GREEN);
+        }
+
+        if let Some(longest) = hikes.iter().max_by_key(|hike| hike.len()) {
+            walked.overlay(longest.iter().copied(), render::RED);
+            frames.push(&walked)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day23>();
+    }
+
+    #[test]
+    fn explains_unreachable_ends() {
+        use utils::Solution;
+
+        let grid = super::Day23::parse("#.###\n#.<.#\n###.#").unwrap();
+        assert_eq!(
+            super::Day23::part1(&grid).failure(),
+            Some("no hike from the start to the end")
+        );
+        assert_eq!(super::Day23::part2(&grid), 4u32.into());
+
+        let walled = super::Day23::parse("#.###\n#.#.#\n###.#").unwrap();
+        assert_eq!(
+            super::Day23::part2(&walled).failure(),
+            Some("no hike from the start to the end")
+        );
+    }
+}
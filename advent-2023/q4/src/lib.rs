@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+
+use utils::{
+    scan::Scanner,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+/// Counts how many of the card's numbers are winning numbers.
+pub fn parse_card(card: &str) -> Result<usize, ParseError> {
+    let mut dupe: u128 = 0;
+    let mut matches = 0;
+
+    let mut scan = Scanner::new(card);
+    if !scan.skip_past(b':') {
+        return Err(ParseError::at_end(card, card, "`:` after the card number"));
+    }
+
+    while let Some(number) = scan
+        .next_number::<u32>()
+        .map_err(|err| err.in_input(card, card))?
+    {
+        // Numbers are tracked as bits of a u128.
+        if number >= 128 {
+            let digits = &card[..scan.position()];
+            let start = digits.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+            return Err(ParseError::at(card, &digits[start..], "a number below 128"));
+        }
+
+        if 1 << number & dupe > 0 {
+            matches += 1;
+        } else {
+            dupe |= 1 << number;
+        }
+    }
+
+    Ok(matches)
+}
+
+pub fn part_1(cards: &[usize]) -> usize {
+    cards
+        .iter()
+        .map(|matches| (1 << matches) >> 1)
+        .sum::<usize>()
+}
+
+pub fn part_2(cards: &[usize]) -> u32 {
+    let mut card_count = vec![1; cards.len()];
+    let mut score = 0;
+
+    for (id, matches) in cards.iter().enumerate() {
+        let count = card_count[id];
+
+        for c in 1..=*matches {
+            card_count[id + c] += count;
+        }
+        score += count;
+    }
+
+    score
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    /// The number of winning numbers on each card.
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_card)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// The points and cards won so far, and the copies won of the cards still to come.
+#[derive(Default)]
+pub struct Cards {
+    points: usize,
+    cards: u32,
+    copies: VecDeque<u32>,
+}
+
+impl Stream for Day4 {
+    type State = Cards;
+
+    fn read_line(cards: &mut Cards, line: &str) -> Result<(), ParseError> {
+        let matches = parse_card(line)?;
+        cards.points += part_1(&[matches]);
+
+        let count = 1 + cards.copies.pop_front().unwrap_or(0);
+        if cards.copies.len() < matches {
+            cards.copies.resize(matches, 0);
+        }
+        for copies in cards.copies.iter_mut().take(matches) {
+            *copies += count;
+        }
+        cards.cards += count;
+        Ok(())
+    }
+
+    fn answer1(cards: &Cards) -> Answer {
+        cards.points.into()
+    }
+
+    fn answer2(cards: &Cards) -> Answer {
+        cards.cards.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day4>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day4>();
+    }
+}
@@ -0,0 +1,172 @@
+use utils::{error::parse_at, Answer, ParseError, Solution};
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    /// Each map's `[destination, source, length]` ranges, in order.
+    maps: Vec<Vec<[usize; 3]>>,
+}
+
+pub fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = input.lines();
+    let seeds = lines.next().unwrap_or(input);
+    let maps = lines.collect::<Vec<_>>();
+
+    let seed_line = seeds;
+    let seeds = seeds
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::at(input, seeds, "`seeds:`"))?
+        .split_ascii_whitespace()
+        .map(|id| parse_at::<usize>(input, id, "a seed number"))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Part 2 reads the seeds as pairs of a start and a length, and needs at least one.
+    if seeds.is_empty() || !seeds.len().is_multiple_of(2) {
+        return Err(ParseError::at_end(
+            input,
+            seed_line,
+            "seed numbers in pairs of a start and a length",
+        ));
+    }
+
+    let maps = maps
+        .split(|l| l.is_empty())
+        .filter(|m| !m.is_empty())
+        .map(|map| {
+            map.iter()
+                .skip(1)
+                .map(|l| {
+                    let mapper = l
+                        .split_ascii_whitespace()
+                        .map(|e| parse_at::<usize>(input, e, "a number"))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    match mapper[..] {
+                        [destination, source, length] => Ok([destination, source, length]),
+                        _ => Err(ParseError::at(input, l, "three numbers")),
+                    }
+                })
+                .collect::<Result<Vec<_>, ParseError>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn part_1(almanac: &Almanac) -> usize {
+    let mut ids = almanac.seeds.clone();
+
+    almanac.maps.iter().for_each(|map| {
+        let mut solved = vec![false; ids.len()];
+        map.iter().for_each(|mapper| {
+            ids.iter_mut().enumerate().for_each(|(i, e)| {
+                let within_range = &mapper[1] <= e && e <= &mut (mapper[1] + mapper[2]);
+
+                if within_range && !solved[i] {
+                    *e = mapper[0] + (*e - mapper[1]);
+                    solved[i] = true;
+                }
+            });
+        });
+    });
+
+    // `parse_almanac` makes sure there is at least one seed.
+    *ids.iter().min().unwrap()
+}
+
+pub fn part_2(almanac: &Almanac) -> usize {
+    let mut ids = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|e| e[0]..(e[0] + e[1]))
+        .collect::<Vec<_>>();
+
+    for map in &almanac.maps {
+        let mut map = map.clone();
+        map.sort_unstable_by(|a, b| a[1].cmp(&b[1]));
+
+        let mut i = 0;
+        loop {
+            if i >= ids.len() {
+                break;
+            }
+            let current_range = ids[i].clone();
+
+            for m in map.iter() {
+                let destination = m[0];
+                let source = m[1];
+                let length = m[2];
+
+                let range = source..(source + length);
+
+                let current_start = current_range.start;
+                let current_end = current_range.end - 1;
+
+                let start_distance = current_start.saturating_sub(source);
+                let end_distance = current_end.saturating_sub(source);
+
+                match (range.contains(&current_start), range.contains(&current_end)) {
+                    (true, true) => {
+                        ids[i] = (destination + start_distance)..(destination + end_distance);
+                        break;
+                    }
+                    (true, false) => {
+                        ids[i] = (destination + start_distance)..(destination + length);
+                        let next_range = (source + length)..current_end + 1;
+                        ids.insert(i + 1, next_range);
+                        break;
+                    }
+                    (false, true) => {
+                        ids[i] = (destination)..(destination + end_distance);
+                        let next_range = (current_start)..(source);
+                        ids.insert(i + 1, next_range);
+                        break;
+                    }
+                    (false, false) => (),
+                }
+            }
+            i += 1;
+        }
+    }
+
+    // Ranges are only ever split, so there is still one for each pair of seeds.
+    ids.iter().map(|range| range.start).min().unwrap()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day5>();
+    }
+
+    #[test]
+    fn rejects_seeds_not_in_pairs() {
+        for seeds in ["seeds:", "seeds: 79 14 55"] {
+            let Err(err) = super::parse_almanac(&format!("{seeds}\n\nseed-to-soil map:\n50 98 2"))
+            else {
+                panic!("{seeds:?} should not parse");
+            };
+            assert_eq!((err.line, err.column), (1, seeds.len() + 1), "{seeds}");
+        }
+    }
+}
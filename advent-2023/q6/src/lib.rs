@@ -0,0 +1,169 @@
+use utils::{
+    math,
+    scan::{Integer, Scanner},
+    Answer, ParseError, Solution,
+};
+
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    /// How many whole hold times beat the record, which are those strictly between the
+    /// roots of `hold * (time - hold) = distance`.
+    fn solutions(&self) -> u64 {
+        let (time, distance) = (self.time, self.distance);
+        let Some(discriminant) = time
+            .checked_mul(time)
+            .zip(distance.checked_mul(4))
+            .and_then(|(square, distance)| square.checked_sub(distance))
+        else {
+            return 0;
+        };
+
+        // At or just below the first winning hold time, then stepped up to it.
+        let mut low = (time - math::isqrt(discriminant)) / 2;
+        while low * (time - low) <= distance {
+            low += 1;
+            if 2 * low > time {
+                return 0;
+            }
+        }
+
+        // The wins are symmetric about half the race time.
+        time - 2 * low + 1
+    }
+}
+
+fn parse_races(input: &str, times: &str, distances: &str) -> Result<Vec<Race>, ParseError> {
+    let mut time_scan = Scanner::new(times);
+    let mut distance_scan = Scanner::new(distances);
+    let mut races = Vec::new();
+
+    while let Some(time) = time_scan
+        .next_number()
+        .map_err(|err| err.in_input(input, times))?
+    {
+        match distance_scan
+            .next_number()
+            .map_err(|err| err.in_input(input, distances))?
+        {
+            Some(distance) => races.push(Race { time, distance }),
+            None => {
+                return Err(ParseError::at_end(
+                    input,
+                    distances,
+                    "a distance for every time",
+                ))
+            }
+        }
+    }
+    Ok(races)
+}
+
+/// Reads every digit in `line`, a line of `input`, as a single number.
+fn parse_kerned(input: &str, line: &str) -> Result<u64, ParseError> {
+    let mut scan = Scanner::new(line);
+    let mut value = 0u64;
+
+    while let Some(digit) = scan.next_digit() {
+        value = value
+            .push_digit(digit)
+            .ok_or_else(|| ParseError::at(input, line, "digits that fit in u64"))?;
+    }
+    Ok(value)
+}
+
+/// The races as written, and read again with the spaces between digits ignored.
+pub struct Sheet {
+    races: Vec<Race>,
+    kerned: Vec<Race>,
+}
+
+pub fn parse_sheet(input: &str) -> Result<Sheet, ParseError> {
+    let mut lines = input.lines();
+    let times = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "`Time:`", ""))?;
+    let distances = lines
+        .next()
+        .ok_or_else(|| ParseError::at_end(input, input, "`Distance:`"))?;
+
+    let races = parse_races(input, times, distances)?;
+    let kerned = vec![Race {
+        time: parse_kerned(input, times)?,
+        distance: parse_kerned(input, distances)?,
+    }];
+
+    Ok(Sheet { races, kerned })
+}
+
+pub fn part_1(sheet: &Sheet) -> usize {
+    sheet
+        .races
+        .iter()
+        .map(|race| race.solutions())
+        .fold(1, |acc, val| acc * val as usize)
+}
+
+pub fn part_2(sheet: &Sheet) -> usize {
+    sheet
+        .kerned
+        .iter()
+        .map(|race| race.solutions())
+        .fold(1, |acc, val| acc * val as usize)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_sheet(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn answers() {
+        utils::expected::check::<Day6>();
+    }
+
+    #[test]
+    fn counts_no_solutions_when_the_discriminant_overflows() {
+        for (time, distance) in [(u64::MAX, 1), (10, u64::MAX)] {
+            assert_eq!(Race { time, distance }.solutions(), 0, "{time} {distance}");
+        }
+    }
+
+    /// A race time, and a record short enough that the race can be won.
+    fn race() -> impl Strategy<Value = (u64, u64)> {
+        (2..5000u64).prop_flat_map(|time| (Just(time), 0..time * time / 4))
+    }
+
+    proptest! {
+        #[test]
+        fn solutions_match_trying_every_hold_time((time, distance) in race()) {
+            let wins = (0..=time).filter(|hold| hold * (time - hold) > distance).count();
+            prop_assert_eq!(Race { time, distance }.solutions(), wins as u64);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use utils::{
+    scan::Scanner,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+type Counts = [u32; 13];
+
+/// A hand's five cards, as written, and its bid.
+pub type Play = ([u8; 5], u32);
+
+struct Hand {
+    bid: u32,
+    strength: u32,
+}
+
+impl Hand {
+    fn new(&(cards, bid): &Play) -> Self {
+        let mut strength = 0u32;
+
+        let mut counts: Counts = [0; 13];
+        for (i, card) in cards.into_iter().enumerate() {
+            let value = match card {
+                b'A' => 12,
+                b'K' => 11,
+                b'Q' => 10,
+                b'J' => 9,
+                b'T' => 8,
+                n => n - b'0' - 2,
+            };
+            strength |= (value as u32) << ((4 - i) * 4);
+            counts[value as usize] += 1;
+        }
+
+        let (max, sec) = find_two_highest(&counts);
+        strength |= calculate_type(max, sec) << 20;
+
+        Hand { bid, strength }
+    }
+
+    fn new_with_jokers(&(cards, bid): &Play) -> Self {
+        let mut strength = 0;
+        let mut jokers = 0;
+
+        let mut counts: Counts = [0; 13];
+        for (i, card) in cards.into_iter().enumerate() {
+            let value = match card {
+                b'A' => 12,
+                b'K' => 11,
+                b'Q' => 10,
+                b'J' => 0,
+                b'T' => 9,
+                n => n - b'0' - 1,
+            };
+
+            if value == 0 {
+                jokers += 1;
+            } else {
+                counts[value as usize] += 1;
+            }
+
+            strength |= (value as u32) << ((4 - i) * 4);
+        }
+
+        let (max, sec) = find_two_highest(&counts);
+        strength |= calculate_type(max + jokers, sec) << 20;
+
+        Hand { bid, strength }
+    }
+}
+
+pub fn parse_play(line: &str) -> Result<Play, ParseError> {
+    let (cards, bid) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at_end(line, line, "a bid after the hand"))?;
+    if let Some(i) = cards.find(|c| !"23456789TJQKA".contains(c)) {
+        return Err(ParseError::at(line, &cards[i..i + 1], "a card"));
+    }
+    let cards = cards
+        .as_bytes()
+        .try_into()
+        .map_err(|_| ParseError::at(line, cards, "five cards"))?;
+    let bid = Scanner::new(bid)
+        .next_number()
+        .map_err(|err| err.in_input(line, bid))?
+        .ok_or_else(|| ParseError::at(line, bid, "a bid"))?;
+
+    Ok((cards, bid))
+}
+
+pub fn part_1(plays: &[Play]) -> usize {
+    let mut hands: Vec<Hand> = plays.iter().map(Hand::new).collect();
+
+    hands.sort_unstable_by_key(|hand| hand.strength);
+
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, hand)| acc + ((i + 1) * hand.bid as usize))
+}
+
+pub fn part_2(plays: &[Play]) -> usize {
+    let mut hands: Vec<Hand> = plays.iter().map(Hand::new_with_jokers).collect();
+
+    hands.sort_unstable_by_key(|hand| hand.strength);
+
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, hand)| acc + ((i + 1) * hand.bid as usize))
+}
+
+fn find_two_highest(counts: &Counts) -> (u32, u32) {
+    let (mut max, mut sec) = (0, 0);
+
+    for count in counts.iter() {
+        if *count > max {
+            sec = max;
+            max = *count;
+        } else if *count > sec {
+            sec = *count;
+        }
+    }
+
+    (max, sec)
+}
+
+fn calculate_type(most_matches: u32, second_most_matches: u32) -> u32 {
+    match most_matches {
+        5 => 6,
+        4 => 5,
+        3 if second_most_matches == 2 => 4,
+        3 => 3,
+        2 if second_most_matches == 2 => 2,
+        2 => 1,
+        _ => 0,
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Play>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_play)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+/// Ranking a hand needs every other hand, so each play is kept. That is a small part of
+/// the text it was read from, at least.
+impl Stream for Day7 {
+    type State = Vec<Play>;
+
+    fn read_line(plays: &mut Vec<Play>, line: &str) -> Result<(), ParseError> {
+        plays.push(parse_play(line)?);
+        Ok(())
+    }
+
+    fn answer1(plays: &Vec<Play>) -> Answer {
+        part_1(plays).into()
+    }
+
+    fn answer2(plays: &Vec<Play>) -> Answer {
+        part_2(plays).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day7>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day7>();
+    }
+}
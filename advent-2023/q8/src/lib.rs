@@ -0,0 +1,168 @@
+use regex::Regex;
+use std::collections::HashMap;
+
+use utils::{cycle, Answer, ParseError, Solution};
+
+type Map = HashMap<String, (String, String)>;
+
+#[derive(Debug)]
+pub struct Network {
+    instructions: String,
+    map: Map,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        match pt1(input) {
+            Some(steps) => steps.into(),
+            None => Answer::failed("no path from `AAA` to `ZZZ`"),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match pt2(input) {
+            Some(steps) => steps.into(),
+            None => Answer::failed("the paths from nodes ending in `A` never line up"),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    let mut map: HashMap<String, (String, String)> = HashMap::new();
+    let mut lines = input.trim().lines();
+    let instructions = lines.next().unwrap_or(input);
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, instructions, "`L` or `R`"));
+    }
+    if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+        let found = &instructions[i..i + 1];
+        return Err(ParseError::at(input, found, "`L` or `R`"));
+    }
+
+    let reg = Regex::new(r"^([A-Z0-9]{3}) = \(([A-Z0-9]{3}), ([A-Z0-9]{3})\)$").unwrap();
+    let mut targets = Vec::new();
+    for node in lines.skip(1) {
+        let (_, [node_name, element_1, element_2]) = reg
+            .captures(node)
+            .ok_or_else(|| ParseError::at(input, node, "a node like `AAA = (BBB, CCC)`"))?
+            .extract();
+        map.insert(
+            node_name.to_string(),
+            (element_1.to_string(), element_2.to_string()),
+        );
+        targets.extend([element_1, element_2]);
+    }
+
+    if let Some(missing) = targets
+        .into_iter()
+        .find(|target| !map.contains_key(*target))
+    {
+        return Err(ParseError::at(
+            input,
+            missing,
+            "a node defined in the network",
+        ));
+    }
+
+    Ok(Network {
+        instructions: instructions.to_string(),
+        map,
+    })
+}
+
+/// Steps from `AAA` to `ZZZ`, if there are both and one leads to the other.
+pub fn pt1(network: &Network) -> Option<usize> {
+    let instructions = network.instructions.as_bytes();
+    let mut cursor = network.map.get_key_value("AAA")?.0;
+
+    // After this many steps the path has been at every node at every point in the
+    // instructions, so it is going round in circles.
+    let most = network.map.len() * instructions.len();
+    for steps in 0..=most {
+        if cursor == "ZZZ" {
+            return Some(steps);
+        }
+        let (left, right) = &network.map[cursor];
+        cursor = if instructions[steps % instructions.len()] == b'L' {
+            left
+        } else {
+            right
+        };
+    }
+
+    None
+}
+
+/// Steps until every path from a node ending in `A` is on a node ending in `Z` at once, or
+/// `None` if there are no such paths or they never line up.
+///
+/// Each path loops once it is back on a node at the same point in the instructions, so
+/// the answer is where the loops first line up on nodes ending in `Z`.
+pub fn pt2(network: &Network) -> Option<u64> {
+    let instructions = network.instructions.as_bytes();
+    let mut paths = Vec::new();
+
+    for start in network.map.keys().filter(|key| key.ends_with('A')) {
+        let (mut taken, mut goals) = (0, Vec::new());
+        // A node and how far through the instructions the path is.
+        let mut state = (start.as_str(), 0);
+        let found = cycle::find(&mut state, |(node, i)| {
+            if node.ends_with('Z') {
+                goals.push(taken);
+            }
+            let (left, right) = &network.map[*node];
+            *node = if instructions[*i] == b'L' {
+                left
+            } else {
+                right
+            };
+            *i = (*i + 1) % instructions.len();
+            taken += 1;
+        });
+        paths.push((found, goals));
+    }
+
+    cycle::first_together(&paths).map(|steps| steps as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day8>();
+    }
+
+    #[test]
+    fn rejects_broken_networks() {
+        let err = super::parse("").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = super::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (3, 8, "BBB"));
+    }
+
+    #[test]
+    fn reports_missing_paths() {
+        use utils::Solution;
+
+        let network = super::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(super::pt1(&network), None);
+        assert_eq!(super::pt2(&network), None);
+        let network = super::parse("R\n\nBBB = (BBB, BBB)\n").unwrap();
+        assert_eq!(super::pt1(&network), None);
+        assert_eq!(
+            super::Day8::part1(&network).failure(),
+            Some("no path from `AAA` to `ZZZ`")
+        );
+    }
+}
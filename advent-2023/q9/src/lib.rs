@@ -0,0 +1,87 @@
+use utils::{
+    error::parse_at,
+    math,
+    stream::{self, Stream},
+    Answer, ParseError, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    /// Every history of the report.
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        stream::parse_lines(input, parse_history)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part_1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part_2(input).into()
+    }
+}
+
+pub fn parse_history(line: &str) -> Result<Vec<i64>, ParseError> {
+    line.split_whitespace()
+        .map(|s| parse_at(line, s, "a number"))
+        .collect()
+}
+
+pub fn part_1(histories: &[Vec<i64>]) -> i64 {
+    solve(histories, true)
+}
+pub fn part_2(histories: &[Vec<i64>]) -> i64 {
+    solve(histories, false)
+}
+
+fn solve(histories: &[Vec<i64>], p1: bool) -> i64 {
+    histories.iter().map(|nums| calc(nums, p1)).sum()
+}
+
+/// The value after the end of `nums`, or before the start for part 2.
+fn calc(nums: &[i64], p1: bool) -> i64 {
+    let at = if p1 { nums.len() as i64 } else { -1 };
+    math::extrapolate(nums, at)
+}
+
+/// The sums so far of the values extrapolated after and before each history.
+#[derive(Default)]
+pub struct Extrapolated(i64, i64);
+
+impl Stream for Day9 {
+    type State = Extrapolated;
+
+    fn read_line(sums: &mut Extrapolated, line: &str) -> Result<(), ParseError> {
+        let history = parse_history(line)?;
+        sums.0 += calc(&history, true);
+        sums.1 += calc(&history, false);
+        Ok(())
+    }
+
+    fn answer1(sums: &Extrapolated) -> Answer {
+        sums.0.into()
+    }
+
+    fn answer2(sums: &Extrapolated) -> Answer {
+        sums.1.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day9>();
+    }
+
+    #[test]
+    fn streams() {
+        utils::stream::check::<super::Day9>();
+    }
+}
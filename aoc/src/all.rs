@@ -0,0 +1,217 @@
+//! `aoc all`: solves every registered day at once, spread over a thread per core, and
+//! sums them up in one table.
+
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use utils::{
+    input::{self, Source},
+    puzzles, Answer,
+};
+
+use crate::{
+    days::Day,
+    inputs::{print_table, time},
+    report::{self, Record, Status},
+};
+
+/// Solves one part of `day` for its default input, turning a panic into an error record so
+/// one broken day doesn't stop the rest.
+fn solve_part(day: &Day, part: usize) -> Vec<Record> {
+    let source = Source::File(input::default_path(day.year, day.day));
+    match panic::catch_unwind(AssertUnwindSafe(|| report::solve(day, &source, Some(part)))) {
+        Ok(records) => records,
+        Err(_) => vec![Record {
+            year: day.year,
+            day: day.day,
+            part,
+            input: "input.txt".to_string(),
+            answer: None,
+            expected: None,
+            status: Status::Error,
+            parse: None,
+            solve: None,
+            memory: None,
+            error: Some(format!(
+                "{} part {} panicked",
+                puzzles::name(day.year, day.day),
+                part
+            )),
+        }],
+    }
+}
+
+/// Solves every part of every one of `days`, or just `part` of each, on whichever thread is
+/// free next. Each part parses its own input, so the two parts of a slow day run side by
+/// side and hold up only their own threads. The records come back sorted by year, day and
+/// part.
+pub fn solve_all(days: &[&Day], part: Option<usize>) -> Vec<Record> {
+    let parts = [1, 2]
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .collect::<Vec<_>>();
+    let work = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .filter(|(day, part)| puzzles::solves(day.year, day.day, *part))
+        .collect::<Vec<_>>();
+
+    let next = AtomicUsize::new(0);
+    let workers = thread::available_parallelism()
+        .map_or(4, |n| n.get())
+        .min(work.len());
+
+    let mut records = thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut records = Vec::new();
+                    while let Some(&(day, part)) = work.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        records.extend(solve_part(day, part));
+                    }
+                    records
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("days are solved without panicking"))
+            .collect::<Vec<_>>()
+    });
+
+    records.sort_by_key(|record| (record.year, record.day, record.part));
+    records
+}
+
+/// The time spent on each year: parsing each day's input once, plus solving each part, as
+/// if the days had been solved one after another.
+fn year_totals(records: &[Record]) -> BTreeMap<u16, Duration> {
+    let mut totals = BTreeMap::new();
+    for day in records.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        let parse = day[0].parse.unwrap_or_default();
+        let solve = day
+            .iter()
+            .filter_map(|record| record.solve)
+            .sum::<Duration>();
+        *totals.entry(day[0].year).or_default() += parse + solve;
+    }
+    totals
+}
+
+/// Prints one row per part with its answer, status and timings, and what it allocated if
+/// that was counted, then how long each year took and the wall clock time for the whole run.
+pub fn print_summary(records: &[Record], elapsed: Duration) {
+    let counted = records.iter().any(|record| record.memory.is_some());
+    let mut header = vec!["Year", "Day", "Part", "Answer", "Status", "Parse", "Solve"];
+    if counted {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+    let rows = records
+        .iter()
+        .map(|record| {
+            let mut row = vec![
+                record.year.to_string(),
+                record.day.to_string(),
+                record.part.to_string(),
+                record
+                    .answer
+                    .as_ref()
+                    .map_or(String::new(), Answer::to_string),
+                report::describe(record.status, record.expected.as_ref()),
+                time(record.parse),
+                time(record.solve),
+            ];
+            if counted {
+                let memory = record.memory.unwrap_or_default();
+                row.extend([
+                    memory.allocations.to_string(),
+                    memory.bytes(),
+                    memory.peak(),
+                ]);
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+    print_table(&header, &rows);
+
+    // Both parts of a day fail to read or parse the input the same way, so one message will do.
+    let mut errors = records
+        .iter()
+        .filter_map(|record| record.error.as_ref())
+        .collect::<Vec<_>>();
+    errors.dedup();
+    if !errors.is_empty() {
+        println!();
+        for error in errors {
+            println!("{error}");
+        }
+    }
+
+    println!();
+    let totals = year_totals(records)
+        .into_iter()
+        .map(|(year, total)| vec![year.to_string(), time(Some(total))])
+        .collect::<Vec<_>>();
+    print_table(&["Year", "Total"], &totals);
+    println!("\nFinished in {:.2?}", elapsed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(year: u16, day: u8, part: usize, parse: u64, solve: u64) -> Record {
+        Record {
+            year,
+            day,
+            part,
+            input: "input.txt".to_string(),
+            answer: None,
+            expected: None,
+            status: Status::Unknown,
+            parse: Some(Duration::from_millis(parse)),
+            solve: Some(Duration::from_millis(solve)),
+            memory: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn solves_every_part_in_order() {
+        let days = crate::days::DAYS
+            .iter()
+            .filter(|day| day.year == 2022)
+            .collect::<Vec<_>>();
+
+        let records = solve_all(&days, None);
+        let order = records
+            .iter()
+            .map(|record| (record.day, record.part))
+            .collect::<Vec<_>>();
+        let expected = (1..=7)
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .collect::<Vec<_>>();
+        assert_eq!(order, expected);
+        assert!(records.iter().all(|r| r.status == Status::Correct));
+    }
+
+    #[test]
+    fn totals_count_each_parse_once() {
+        let records = [
+            record(2022, 1, 1, 5, 1),
+            record(2022, 1, 2, 5, 2),
+            record(2023, 1, 1, 1, 10),
+            record(2023, 2, 2, 1, 10),
+        ];
+
+        let totals = year_totals(&records);
+        assert_eq!(totals[&2022], Duration::from_millis(8));
+        assert_eq!(totals[&2023], Duration::from_millis(22));
+    }
+}
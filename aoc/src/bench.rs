@@ -0,0 +1,52 @@
+//! `aoc bench`: times parsing and each part of a day over many runs.
+
+use utils::{
+    bench::{self, Config, Stats},
+    input::Source,
+    puzzles, run,
+};
+
+use crate::days::Day;
+
+fn stats_line(label: &str, stats: &Stats) -> String {
+    format!(
+        "{:<7} {:>10.2?} ± {:<10.2?} | Range: {:.2?} - {:.2?} | Samples: {}",
+        label, stats.median, stats.spread, stats.min, stats.max, stats.samples
+    )
+}
+
+pub fn bench_day(day: &Day, source: &Source, part: Option<usize>) -> bool {
+    println!("{}", run::banner(day.year, day.day));
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            println!("Could not read {source}: {err}");
+            return false;
+        }
+    };
+
+    let parsed = match (day.parse)(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            println!("Could not parse {source}: {err}");
+            return false;
+        }
+    };
+
+    let config = Config::default();
+    let stats = bench::bench(&config, || (day.parse)(&input));
+    println!("{}", stats_line("Parse:", &stats));
+
+    let solves = |p| part.is_none_or(|part| part == p) && puzzles::solves(day.year, day.day, p);
+    if solves(1) {
+        let stats = bench::bench(&config, || parsed.part1());
+        println!("{}", stats_line("Part 1:", &stats));
+    }
+    if solves(2) {
+        let stats = bench::bench(&config, || parsed.part2());
+        println!("{}", stats_line("Part 2:", &stats));
+    }
+
+    true
+}
@@ -0,0 +1,56 @@
+//! Every solved day, from the library in its own crate.
+
+use utils::{ParseError, Parsed, Solution};
+
+/// Parses a day's raw input, ready for either part to be solved.
+pub type Parse = fn(&str) -> Result<Box<dyn Parsed>, ParseError>;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Parse,
+}
+
+const fn day<S: Solution + 'static>() -> Day
+where
+    S::Input: 'static,
+{
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        parse: utils::parse::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<advent_2016_q3::Day3>(),
+    day::<advent_2016_q4::Day4>(),
+    day::<advent_2022_day1::Day1>(),
+    day::<advent_2022_day2::Day2>(),
+    day::<advent_2022_day3::Day3>(),
+    day::<advent_2022_day4::Day4>(),
+    day::<advent_2022_day5::Day5>(),
+    day::<advent_2022_day6::Day6>(),
+    day::<advent_2022_day7::Day7>(),
+    day::<advent_2023_q1::Day1>(),
+    day::<advent_2023_q2::Day2>(),
+    day::<advent_2023_q4::Day4>(),
+    day::<advent_2023_q5::Day5>(),
+    day::<advent_2023_q6::Day6>(),
+    day::<advent_2023_q7::Day7>(),
+    day::<advent_2023_q8::Day8>(),
+    day::<advent_2023_q9::Day9>(),
+    day::<advent_2023_q11::Day11>(),
+    day::<advent_2023_q12::Day12>(),
+    day::<advent_2023_q13::Day13>(),
+    day::<advent_2023_q14::Day14>(),
+    day::<advent_2023_q15::Day15>(),
+    day::<advent_2023_q16::Day16>(),
+    day::<advent_2023_q17::Day17>(),
+    day::<advent_2023_q18::Day18>(),
+    day::<advent_2023_q19::Day19>(),
+    day::<advent_2023_q20::Day20>(),
+    day::<advent_2023_q21::Day21>(),
+    day::<advent_2023_q22::Day22>(),
+    day::<advent_2023_q23::Day23>(),
+];
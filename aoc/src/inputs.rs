@@ -0,0 +1,123 @@
+//! `aoc inputs`: solves a day for every input in a folder, so answers can be compared across
+//! everyone's inputs.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use utils::{input::Source, run, Answer};
+
+use crate::{
+    days::Day,
+    report::{self, Record, Status},
+};
+
+/// Every input file in `dir`, by name, leaving out the day's `answers.toml`.
+fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if path.is_file() && name != "answers.toml" && !name.starts_with('.') {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Prints `rows` as left aligned columns under `header`.
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths = header.iter().map(|h| h.len()).collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let padded = cells
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .collect::<Vec<_>>();
+        println!("{}", padded.join("  ").trim_end());
+    };
+
+    line(&mut header.iter().copied());
+    for row in rows {
+        line(&mut row.iter().map(String::as_str));
+    }
+}
+
+pub fn time(elapsed: Option<Duration>) -> String {
+    elapsed.map_or(String::new(), |elapsed| format!("{:.2?}", elapsed))
+}
+
+/// Solves `day` for every input in `dir`.
+pub fn solve_inputs(day: &Day, dir: &Path, part: Option<usize>) -> Result<Vec<Record>, String> {
+    let files =
+        input_files(dir).map_err(|err| format!("Could not read {}: {}", dir.display(), err))?;
+
+    Ok(files
+        .into_iter()
+        .flat_map(|path| report::solve(day, &Source::File(path), part))
+        .collect())
+}
+
+/// Prints one row per input with its answers, the answers expected for it in the folder's
+/// `answers.toml`, and how long each step took.
+pub fn print_inputs(day: &Day, records: &[Record]) {
+    println!("{}", run::banner(day.year, day.day));
+
+    let mut parts = records.iter().map(|record| record.part).collect::<Vec<_>>();
+    parts.sort_unstable();
+    parts.dedup();
+
+    let mut header = vec!["Input"];
+    for &p in &parts {
+        header.extend([["Part 1", "Part 2"][p - 1], "Expected"]);
+    }
+    header.push("Parse");
+    for &p in &parts {
+        header.push(["Time 1", "Time 2"][p - 1]);
+    }
+
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+
+    // Each input's records are next to each other, one for each part.
+    for input in records.chunk_by(|a, b| a.input == b.input) {
+        if let Some(error) = input.iter().find_map(|record| record.error.as_ref()) {
+            failures.push(error);
+            continue;
+        }
+
+        let mut row = vec![input[0].input.clone()];
+        for record in input {
+            let expected = match (&record.expected, record.status) {
+                (Some(expected), Status::Wrong) => format!("{expected} (wrong)"),
+                (Some(expected), _) => expected.to_string(),
+                (None, _) => "-".to_string(),
+            };
+            let answer = record
+                .answer
+                .as_ref()
+                .map_or(String::new(), Answer::to_string);
+            row.extend([answer, expected]);
+        }
+        row.push(time(input[0].parse));
+        row.extend(input.iter().map(|record| time(record.solve)));
+        rows.push(row);
+    }
+
+    print_table(&header, &rows);
+    for failure in failures {
+        println!("{failure}");
+    }
+}
@@ -0,0 +1,324 @@
+mod all;
+mod bench;
+mod days;
+mod inputs;
+mod new;
+mod report;
+mod watch;
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use days::{Day, DAYS};
+use report::{Format, Record, Status};
+use utils::{
+    input::{self, Source},
+    puzzles, run,
+};
+
+/// The workspace holding every day's crate.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const USAGE: &str = "usage: aoc [bench|inputs|accept|watch|new] <year> [day] [--part <1|2>]
+           [--input <path|->] [--format <text|json|csv>]
+       aoc all [--part <1|2>] [--format <text|json|csv>]
+
+  bench   time each day over many runs
+  inputs  solve each day for every input in its folder, or the folder given by --input
+  accept  solve each day and record any new answers in the answers.toml next to its input
+  watch   rebuild and re-run one day whenever its source, input or answers change
+  new     start a day's crate and input folder, and add it to the runner
+  all     solve every day of every year at once and sum them up in one table
+
+Answers are checked against the answers.toml next to each input, and the exit status is 1
+if any is wrong or any day could not be solved.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Inputs,
+    Accept,
+    Watch,
+    New,
+    All,
+}
+
+struct Args {
+    mode: Mode,
+    /// The year to solve, which is every year in `all` mode.
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<usize>,
+    /// Input to use instead of the day's default, only allowed when running one day. In
+    /// `inputs` mode this is a folder of inputs.
+    input: Option<String>,
+    format: Format,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut part = None;
+        let mut input = None;
+        let mut format = Format::Text;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-p" | "--part" => {
+                    let value = args.next().ok_or("--part needs a value")?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("part must be 1 or 2, got `{value}`")),
+                    };
+                }
+                "-i" | "--input" => {
+                    input = Some(args.next().ok_or("--input needs a value")?);
+                }
+                "-f" | "--format" => {
+                    let value = args.next().ok_or("--format needs a value")?;
+                    format = Format::parse(&value)
+                        .ok_or(format!("format must be text, json or csv, got `{value}`"))?;
+                }
+                flag if flag.starts_with('-') => return Err(format!("unknown flag `{flag}`")),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter().peekable();
+        let mode = match positional.peek().map(String::as_str) {
+            Some("bench") => Mode::Bench,
+            Some("inputs") => Mode::Inputs,
+            Some("accept") => Mode::Accept,
+            Some("watch") => Mode::Watch,
+            Some("new") => Mode::New,
+            Some("all") => Mode::All,
+            _ => Mode::Run,
+        };
+        if mode != Mode::Run {
+            positional.next();
+        }
+        let (year, day) = match mode {
+            Mode::All => (None, None),
+            _ => {
+                let year = positional
+                    .next()
+                    .ok_or("missing year")?
+                    .parse()
+                    .map_err(|_| "year must be a number")?;
+                let day = positional
+                    .next()
+                    .map(|day| day.parse().map_err(|_| "day must be a number"))
+                    .transpose()?;
+                (Some(year), day)
+            }
+        };
+
+        if let Some(extra) = positional.next() {
+            return Err(format!("unexpected argument `{extra}`"));
+        }
+
+        if input.is_some() && day.is_none() {
+            return Err("--input needs a day".to_string());
+        }
+        if mode == Mode::Bench && format != Format::Text {
+            return Err("bench only prints text".to_string());
+        }
+        if mode == Mode::New && (day.is_none() || part.is_some() || input.is_some()) {
+            return Err("new takes just a year and a day".to_string());
+        }
+        if mode == Mode::Watch {
+            if day.is_none() {
+                return Err("watch needs a day".to_string());
+            }
+            if format != Format::Text {
+                return Err("watch only prints text".to_string());
+            }
+        }
+
+        Ok(Args {
+            mode,
+            year,
+            day,
+            part,
+            input,
+            format,
+        })
+    }
+}
+
+/// Prints the answers to each part of a day, or why it could not be solved.
+fn print_day(day: &Day, records: &[Record]) {
+    println!("{}", run::banner(day.year, day.day));
+
+    for record in records {
+        match (&record.answer, record.solve) {
+            (Some(answer), Some(elapsed)) => {
+                let status = report::describe(record.status, record.expected.as_ref());
+                let line = run::part_line(record.part, answer, elapsed);
+                match record.memory {
+                    Some(memory) => println!("{line:<48} | {status} | {memory}"),
+                    None => println!("{line:<48} | {status}"),
+                }
+            }
+            _ => {
+                // Every part fails the same way, so one message will do.
+                println!("{}", record.error.as_deref().unwrap_or("No answer"));
+                break;
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if let (Mode::New, Some(year), Some(day)) = (args.mode, args.year, args.day) {
+        return match new::scaffold(Path::new(ROOT), year, day) {
+            Ok(files) => {
+                for file in files {
+                    let file = file.strip_prefix(ROOT).unwrap_or(&file);
+                    println!("Wrote {}", file.display());
+                }
+                if puzzles::find(year, day).is_none_or(|puzzle| puzzle.title.is_empty()) {
+                    println!("Fill in the puzzle's title and input in utils/src/puzzles.rs");
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let days = DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|y| y == day.year))
+        .filter(|day| args.day.is_none_or(|d| d == day.day))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        let year = args.year.unwrap_or_default();
+        match args.day {
+            Some(day) => eprintln!("No solution for {} day {}", year, day),
+            None => eprintln!("No solutions for {}", year),
+        }
+        return ExitCode::FAILURE;
+    }
+
+    if args.mode == Mode::All {
+        let started = Instant::now();
+        let records = all::solve_all(&days, args.part);
+        if args.format == Format::Text {
+            all::print_summary(&records, started.elapsed());
+        }
+        return finish(args.format, &records, true);
+    }
+
+    let mut ok = true;
+    let mut all_records = Vec::new();
+
+    for day in days {
+        // A whole year always uses the default inputs, so `AOC_INPUT` only applies to one day.
+        let source = match args.day {
+            Some(_) => Source::resolve(day.year, day.day, args.input.as_deref()),
+            None => Source::File(input::default_path(day.year, day.day)),
+        };
+
+        let records = match args.mode {
+            Mode::Watch => return watch::watch(day, &source, args.part),
+            Mode::New | Mode::All => unreachable!("handled before solving days one at a time"),
+            Mode::Bench => {
+                ok &= bench::bench_day(day, &source, args.part);
+                continue;
+            }
+            Mode::Run | Mode::Accept => report::solve(day, &source, args.part),
+            Mode::Inputs => {
+                let dir = match &args.input {
+                    Some(dir) => PathBuf::from(dir),
+                    None => input::dir(day.year, day.day),
+                };
+                match inputs::solve_inputs(day, &dir, args.part) {
+                    Ok(records) => records,
+                    Err(err) => {
+                        if args.format == Format::Text {
+                            println!("{}", run::banner(day.year, day.day));
+                            println!("{err}");
+                        } else {
+                            eprintln!("{err}");
+                        }
+                        ok = false;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        match (args.format, args.mode) {
+            (Format::Text, Mode::Inputs) => inputs::print_inputs(day, &records),
+            (Format::Text, _) => print_day(day, &records),
+            _ => (),
+        }
+
+        if args.mode == Mode::Accept && !records.iter().any(|r| r.status == Status::Error) {
+            match report::accept(&source, &records) {
+                Ok((_, added)) if added.is_empty() => (),
+                Ok((path, added)) => {
+                    let parts = match added[..] {
+                        [part] => format!("the answer to part {part}"),
+                        _ => "the answers to both parts".to_string(),
+                    };
+                    eprintln!("Recorded {} in {}", parts, path.display());
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    ok = false;
+                }
+            }
+        }
+
+        all_records.extend(records);
+    }
+
+    finish(args.format, &all_records, ok)
+}
+
+/// Prints `records` if they were asked for as JSON or CSV, then says how many answers were
+/// wrong. Fails if any were, any day could not be solved, or the run was not otherwise `ok`.
+fn finish(format: Format, records: &[Record], ok: bool) -> ExitCode {
+    match format {
+        Format::Text => (),
+        Format::Json => println!("{}", report::json(records)),
+        Format::Csv => print!("{}", report::csv(records)),
+    }
+
+    let wrong = records
+        .iter()
+        .filter(|record| record.status == Status::Wrong)
+        .count();
+    if wrong > 0 {
+        eprintln!(
+            "{} answer{} did not match the known answers",
+            wrong,
+            if wrong == 1 { "" } else { "s" }
+        );
+    }
+
+    if ok && !records.iter().any(|record| record.status.is_failure()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,403 @@
+//! `aoc new`: starts a day from a template rather than a copy of another day, with its
+//! input folder ready and the runner already calling it.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::DocumentMut;
+
+const LIB: &str = r#"use utils::{Answer, ParseError, Solution};
+
+pub struct Day$DAY;
+
+impl Solution for Day$DAY {
+    const YEAR: u16 = $YEAR;
+    const DAY: u8 = $DAY;
+
+    /// The lines of the input.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Answer::failed("not solved yet")
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::failed("not solved yet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn answers() {
+        utils::expected::check::<super::Day$DAY>();
+    }
+}
+"#;
+
+const MAIN: &str = r#"use std::process::ExitCode;
+
+use $CRATE::Day$DAY;
+
+fn main() -> ExitCode {
+    utils::run::<Day$DAY>()
+}
+"#;
+
+const MANIFEST: &str = r#"[package]
+name = "$PACKAGE"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+utils.workspace = true
+"#;
+
+const ANSWERS: &str = r#"# Paste the puzzle's example into the input below and add its answers, as `part1 = ...`,
+# as each part is solved. `aoc accept` records the answers to the real input.
+
+[[example]]
+input = '''
+'''
+"#;
+
+/// The folder for a new day's crate in `year`, named like the other days that year: `dayN`
+/// if they are, `qN` otherwise.
+fn crate_folder(root: &Path, year: u16, day: u8) -> String {
+    let days_named_day = fs::read_dir(root.join(format!("advent-{year}")))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.starts_with("day") && entry.path().join("Cargo.toml").is_file()
+        });
+
+    let prefix = if days_named_day { "day" } else { "q" };
+    format!("{prefix}{day}")
+}
+
+/// Writes `contents` to a new file at `path`, failing rather than overwriting one.
+fn create(path: &Path, contents: &str) -> Result<(), String> {
+    let write = || {
+        fs::create_dir_all(path.parent().unwrap_or(Path::new("")))?;
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, contents.as_bytes()))
+    };
+    write().map_err(|err| format!("Could not create {}: {}", path.display(), err))
+}
+
+/// Adds the crate at `member` to the workspace, unless a glob already covers its year.
+fn add_member(manifest: &Path, year: u16, member: &str) -> Result<(), String> {
+    let fail = |err: String| format!("Could not add {member} to {}: {err}", manifest.display());
+
+    let text = fs::read_to_string(manifest).map_err(|err| fail(err.to_string()))?;
+    let mut doc = text
+        .parse::<DocumentMut>()
+        .map_err(|err| fail(err.to_string()))?;
+    let members = doc["workspace"]["members"]
+        .as_array_mut()
+        .ok_or_else(|| fail("`workspace.members` is not a list".to_string()))?;
+
+    let glob = format!("advent-{year}/*");
+    if members
+        .iter()
+        .any(|m| m.as_str() == Some(&glob) || m.as_str() == Some(member))
+    {
+        return Ok(());
+    }
+
+    members.push(member);
+    members.fmt();
+    fs::write(manifest, doc.to_string()).map_err(|err| fail(err.to_string()))
+}
+
+/// Adds the day's crate, named `package`, to the runner's dependencies.
+fn add_dependency(manifest: &Path, package: &str, member: &str) -> Result<(), String> {
+    let fail = |err: String| format!("Could not add {package} to {}: {err}", manifest.display());
+
+    let text = fs::read_to_string(manifest).map_err(|err| fail(err.to_string()))?;
+    let mut doc = text
+        .parse::<DocumentMut>()
+        .map_err(|err| fail(err.to_string()))?;
+    let dependencies = doc["dependencies"]
+        .as_table_mut()
+        .ok_or_else(|| fail("`dependencies` is not a table".to_string()))?;
+    if dependencies.contains_key(package) {
+        return Err(fail("it is already there".to_string()));
+    }
+
+    let mut path = toml_edit::InlineTable::new();
+    path.insert("path", format!("../{member}").into());
+    dependencies.insert(package, toml_edit::value(path));
+    fs::write(manifest, doc.to_string()).map_err(|err| fail(err.to_string()))
+}
+
+/// The year and day of a crate name, like `advent_2023_q17`.
+fn crate_day(name: &str) -> Option<(u16, u8)> {
+    let (year, folder) = name.strip_prefix("advent_")?.split_once('_')?;
+    let day = folder.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Adds the day's entry in `DAYS` to the runner's `days.rs`, keeping them in order.
+fn register(days_rs: &Path, day: u8, name: &str) -> Result<(), String> {
+    let text = fs::read_to_string(days_rs)
+        .map_err(|err| format!("Could not read {}: {}", days_rs.display(), err))?;
+    if text.contains(&format!("day::<{name}::")) {
+        return Err(format!("{} already has {}", days_rs.display(), name));
+    }
+
+    let lines = text.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or_else(|| format!("Could not find DAYS in {}", days_rs.display()))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| format!("Could not find the end of DAYS in {}", days_rs.display()))?;
+
+    let mut entries = lines[start + 1..end]
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    entries.push(format!("    day::<{name}::Day{day}>(),"));
+    entries.sort_by_key(|entry| {
+        let name = entry.trim().trim_start_matches("day::<");
+        crate_day(name.split("::").next().unwrap_or_default())
+    });
+
+    let mut out = lines[..=start]
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    out.extend(entries);
+    out.extend(lines[end..].iter().map(|line| line.to_string()));
+
+    fs::write(days_rs, out.join("\n") + "\n")
+        .map_err(|err| format!("Could not write {}: {}", days_rs.display(), err))
+}
+
+/// The year and day of the entry in `PUZZLES` written as `entry`, like `puzzle(2023, 4, ...)`.
+fn puzzle_day(entry: &str) -> Option<(u16, u8)> {
+    let (_, args) = entry.split_once("puzzle(")?;
+    let mut args = args.split(',').map(str::trim);
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Adds an entry for the day to `PUZZLES` in `puzzles.rs`, keeping them in order, with no
+/// parts solved and the title and input left to fill in. Does nothing if there is one.
+fn add_puzzle(puzzles_rs: &Path, year: u16, day: u8) -> Result<(), String> {
+    let text = fs::read_to_string(puzzles_rs)
+        .map_err(|err| format!("Could not read {}: {}", puzzles_rs.display(), err))?;
+
+    let lines = text.lines().collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const PUZZLES"))
+        .ok_or_else(|| format!("Could not find PUZZLES in {}", puzzles_rs.display()))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or_else(|| {
+                format!(
+                    "Could not find the end of PUZZLES in {}",
+                    puzzles_rs.display()
+                )
+            })?;
+
+    // Each entry starts on a line indented once, and may go on over several.
+    let entries = (start + 1..end)
+        .filter(|&i| lines[i].starts_with("    ") && !lines[i].starts_with("     "))
+        .filter(|&i| !lines[i].trim_start().starts_with(['}', ')']));
+    let mut at = end;
+    for i in entries {
+        let rest = lines[i..end].join(" ");
+        match puzzle_day(&rest) {
+            Some(found) if found == (year, day) => return Ok(()),
+            Some(found) if found > (year, day) => {
+                at = i;
+                break;
+            }
+            _ => (),
+        }
+    }
+
+    let entry = format!(
+        "    Puzzle {{\n        parts: 0,\n        ..puzzle({year}, {day}, \"\", \"\")\n    }},"
+    );
+    let mut out = lines[..at].to_vec();
+    out.push(&entry);
+    out.extend(&lines[at..]);
+
+    fs::write(puzzles_rs, out.join("\n") + "\n")
+        .map_err(|err| format!("Could not write {}: {}", puzzles_rs.display(), err))
+}
+
+/// Creates the crate, input folder, runner entry and puzzle entry for a new day in the
+/// workspace at `root`, returning the files created or changed.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day must be from 1 to 25, got {day}"));
+    }
+
+    let folder = crate_folder(root, year, day);
+    let member = format!("advent-{year}/{folder}");
+    let package = format!("advent-{year}-{folder}");
+    let dir = root.join(&member);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let inputs = root
+        .join("inputs")
+        .join(year.to_string())
+        .join(format!("day{:02}", day));
+    let fill = |template: &str| {
+        template
+            .replace("$YEAR", &year.to_string())
+            .replace("$DAY", &day.to_string())
+            .replace("$PACKAGE", &package)
+            .replace("$CRATE", &package.replace('-', "_"))
+    };
+
+    let files = [
+        (dir.join("Cargo.toml"), fill(MANIFEST)),
+        (dir.join("src/lib.rs"), fill(LIB)),
+        (dir.join("src/main.rs"), fill(MAIN)),
+        (inputs.join("input.txt"), String::new()),
+        (inputs.join("answers.toml"), ANSWERS.to_string()),
+    ];
+    for (path, contents) in &files {
+        create(path, contents)?;
+    }
+
+    let manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let days_rs = root.join("aoc/src/days.rs");
+    let puzzles_rs = root.join("utils/src/puzzles.rs");
+    add_member(&manifest, year, &member)?;
+    add_dependency(&runner_manifest, &package, &member)?;
+    register(&days_rs, day, &package.replace('-', "_"))?;
+    add_puzzle(&puzzles_rs, year, day)?;
+
+    let mut changed = files.map(|(path, _)| path).to_vec();
+    changed.extend([manifest, runner_manifest, days_rs, puzzles_rs]);
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const DAYS_RS: &str = r#"//! Days.
+
+pub const DAYS: &[Day] = &[
+    day::<advent_2022_day1::Day1>(),
+    day::<advent_2023_q1::Day1>(),
+    day::<advent_2023_q2::Day2>(),
+];
+"#;
+
+    const PUZZLES_RS: &str = r#"//! Puzzles.
+
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle(2022, 1, "Calorie Counting", "numbers"),
+    puzzle(
+        2023,
+        2,
+        "Cube Conundrum",
+        "games",
+    ),
+];
+"#;
+
+    const RUNNER_MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+utils.workspace = true
+
+# Every day's solution.
+advent-2022-day1 = { path = "../advent-2022/day1" }
+"#;
+
+    #[test]
+    fn creates_and_registers_a_day() {
+        let root = env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join("utils/src")).unwrap();
+        fs::write(root.join("utils/src/puzzles.rs"), PUZZLES_RS).unwrap();
+        fs::create_dir_all(root.join("advent-2022/day1")).unwrap();
+        fs::write(root.join("advent-2022/day1/Cargo.toml"), "").unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"advent-2023/*\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS_RS).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+
+        scaffold(&root, 2023, 12).unwrap();
+        scaffold(&root, 2022, 3).unwrap();
+        scaffold(&root, 2024, 1).unwrap();
+
+        let lib = fs::read_to_string(root.join("advent-2023/q12/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day12 {\n    const YEAR: u16 = 2023;"));
+        assert!(!lib.contains("todo!()"));
+        let main = fs::read_to_string(root.join("advent-2023/q12/src/main.rs")).unwrap();
+        assert!(main.contains("use advent_2023_q12::Day12;"));
+        let manifest = fs::read_to_string(root.join("advent-2022/day3/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"advent-2022-day3\""));
+        assert!(root.join("inputs/2024/day01/input.txt").is_file());
+        assert!(root.join("inputs/2024/day01/answers.toml").is_file());
+
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"advent-2022/day3\""));
+        assert!(workspace.contains("\"advent-2024/q1\""));
+        assert!(!workspace.contains("advent-2023/q12"));
+
+        let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(runner.contains("advent-2023-q12 = { path = \"../advent-2023/q12\" }"));
+        assert!(runner.contains("advent-2024-q1 = { path = \"../advent-2024/q1\" }"));
+
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains(
+            "    day::<advent_2022_day1::Day1>(),\n    day::<advent_2022_day3::Day3>(),\n"
+        ));
+        assert!(days.contains(
+            "    day::<advent_2023_q2::Day2>(),\n    day::<advent_2023_q12::Day12>(),\n    \
+             day::<advent_2024_q1::Day1>(),\n];"
+        ));
+
+        let puzzles = fs::read_to_string(root.join("utils/src/puzzles.rs")).unwrap();
+        assert!(puzzles.contains(
+            "    puzzle(2022, 1, \"Calorie Counting\", \"numbers\"),\n    Puzzle {\n        \
+             parts: 0,\n        ..puzzle(2022, 3, \"\", \"\")\n    },\n    puzzle(\n        2023,\n"
+        ));
+        assert!(puzzles.contains(
+            "    ),\n    Puzzle {\n        parts: 0,\n        ..puzzle(2023, 12, \"\", \"\")\n    },\n    \
+             Puzzle {\n        parts: 0,\n        ..puzzle(2024, 1, \"\", \"\")\n    },\n];"
+        ));
+
+        assert!(scaffold(&root, 2023, 12)
+            .unwrap_err()
+            .contains("already exists"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
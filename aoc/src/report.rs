@@ -0,0 +1,398 @@
+//! The results of solving a day, one record per part and input, and printing them as JSON
+//! or CSV for scripts and dashboards to read.
+
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+use utils::{
+    alloc::{self, Usage},
+    expected::{self, Answers, Expected, Parts},
+    input::Source,
+    puzzles, Answer, Parsed,
+};
+
+use crate::days::Day;
+
+const SOLVERS: [fn(&dyn Parsed) -> Answer; 2] = [|p| p.part1(), |p| p.part2()];
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    /// The answer matches the known answer.
+    Correct,
+    Wrong,
+    /// There is no known answer to compare with.
+    Unknown,
+    /// The input could not be read or parsed, or the part has no answer for it.
+    Error,
+}
+
+impl Status {
+    /// Whether this is a problem that should fail the run.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Wrong | Status::Error)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Unknown => "unknown",
+            Status::Error => "error",
+        }
+    }
+}
+
+/// How a checked answer is described next to it: its status, or what it should have been
+/// when it is wrong.
+pub fn describe(status: Status, expected: Option<&Expected>) -> String {
+    match (status, expected) {
+        (Status::Wrong, Some(expected)) => format!("WRONG, expected {expected}"),
+        (status, _) => status.as_str().to_string(),
+    }
+}
+
+/// One part of a day solved for one input.
+#[derive(Serialize, Debug, Clone)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: usize,
+    /// The input's file name, or `stdin`.
+    pub input: String,
+    pub answer: Option<Answer>,
+    pub expected: Option<Expected>,
+    pub status: Status,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Option<Duration>,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve: Option<Duration>,
+    /// What solving the part allocated, when built with the `count-allocs` feature.
+    #[serde(flatten)]
+    pub memory: Option<Usage>,
+    /// Why the input could not be solved, when the status is `error`.
+    pub error: Option<String>,
+}
+
+fn nanos<S: Serializer>(time: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    time.map(|time| time.as_nanos() as u64)
+        .serialize(serializer)
+}
+
+/// The `answers.toml` holding the known answers for the input at `path`, which is the one in
+/// the same folder.
+pub fn answers_path(path: &Path) -> PathBuf {
+    path.parent().unwrap_or(Path::new("")).join("answers.toml")
+}
+
+/// The known answers for `source`.
+pub fn expected_for(source: &Source) -> Result<Parts, Box<dyn Error>> {
+    let Source::File(path) = source else {
+        return Ok(Parts::default());
+    };
+
+    let answers = Answers::load_from(&answers_path(path))?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    Ok(answers
+        .inputs
+        .get(name.as_ref())
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Reads the input from `source` and solves `day` for it, giving a record for each part,
+/// or just for `part` if one is given.
+pub fn solve(day: &Day, source: &Source, part: Option<usize>) -> Vec<Record> {
+    let parts = [1, 2]
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .filter(|&p| puzzles::solves(day.year, day.day, p));
+    let input = match source {
+        Source::File(path) => path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into(),
+        Source::Stdin => "stdin".to_string(),
+    };
+
+    let record = |part, status| Record {
+        year: day.year,
+        day: day.day,
+        part,
+        input: input.clone(),
+        answer: None,
+        expected: None,
+        status,
+        parse: None,
+        solve: None,
+        memory: None,
+        error: None,
+    };
+
+    let parsed = expected_for(source)
+        .map_err(|err| format!("Could not read the answers for {source}: {err}"))
+        .and_then(|expected| {
+            let text = source
+                .read()
+                .map_err(|err| format!("Could not read {source}: {err}"))?;
+
+            let before = Instant::now();
+            let parsed =
+                (day.parse)(&text).map_err(|err| format!("Could not parse {source}: {err}"))?;
+            Ok((expected, parsed, before.elapsed()))
+        });
+
+    let (expected, parsed, parse_time) = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            return parts
+                .map(|part| Record {
+                    error: Some(err.clone()),
+                    ..record(part, Status::Error)
+                })
+                .collect()
+        }
+    };
+
+    parts
+        .map(|part| {
+            let before = Instant::now();
+            let (answer, memory) = alloc::measure(|| SOLVERS[part - 1](parsed.as_ref()));
+            let solve_time = before.elapsed();
+
+            let expected = expected.part(part).cloned();
+            let status = match &expected {
+                _ if answer.failure().is_some() => Status::Error,
+                Some(expected) if expected.matches(&answer) => Status::Correct,
+                Some(_) => Status::Wrong,
+                None => Status::Unknown,
+            };
+            let error = answer.failure().map(|why| {
+                format!(
+                    "{} part {} has no answer for {}: {}",
+                    puzzles::name(day.year, day.day),
+                    part,
+                    source,
+                    why
+                )
+            });
+
+            Record {
+                answer: Some(answer),
+                expected,
+                parse: Some(parse_time),
+                solve: Some(solve_time),
+                memory,
+                error,
+                ..record(part, status)
+            }
+        })
+        .collect()
+}
+
+/// Records every answer in `records` that has no known answer yet, all solved from
+/// `source`, in the `answers.toml` next to it. Returns the file written to and the parts
+/// added.
+pub fn accept(source: &Source, records: &[Record]) -> Result<(PathBuf, Vec<usize>), String> {
+    let Source::File(path) = source else {
+        return Err("Answers can only be recorded for input files, not stdin".to_string());
+    };
+
+    let answers = records
+        .iter()
+        .filter(|record| record.status == Status::Unknown)
+        .filter_map(|record| Some((record.part, record.answer.as_ref()?)))
+        .collect::<Vec<_>>();
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let answers_path = answers_path(path);
+
+    let added = expected::accept(&answers_path, &name, &answers).map_err(|err| {
+        format!(
+            "Could not record answers in {}: {}",
+            answers_path.display(),
+            err
+        )
+    })?;
+    Ok((answers_path, added))
+}
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Banners and aligned lines for reading in a terminal.
+    Text,
+    /// A JSON array with one object per record.
+    Json,
+    /// A header line, then one line per record.
+    Csv,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Writes `records` as a JSON array, one record to a line.
+pub fn json(records: &[Record]) -> String {
+    let lines = records
+        .iter()
+        .map(|record| format!("  {}", serde_json::to_string(record).unwrap()))
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        "[]".to_string()
+    } else {
+        format!("[\n{}\n]", lines.join(",\n"))
+    }
+}
+
+/// Quotes a CSV field if it holds a separator, quote or line break.
+fn csv_field(field: String) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// Writes `records` as CSV under a header naming the columns.
+pub fn csv(records: &[Record]) -> String {
+    let mut out = String::from(
+        "year,day,part,input,answer,expected,status,parse_ns,solve_ns,allocations,allocated_bytes,\
+         peak_bytes,error\n",
+    );
+
+    for record in records {
+        let nanos =
+            |time: Option<Duration>| time.map_or(String::new(), |t| t.as_nanos().to_string());
+        let count = |field: fn(&Usage) -> u64| {
+            record
+                .memory
+                .as_ref()
+                .map_or(String::new(), |memory| field(memory).to_string())
+        };
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            record.input.clone(),
+            record
+                .answer
+                .as_ref()
+                .filter(|answer| answer.failure().is_none())
+                .map_or(String::new(), Answer::to_string),
+            record
+                .expected
+                .as_ref()
+                .map_or(String::new(), Expected::to_string),
+            record.status.as_str().to_string(),
+            nanos(record.parse),
+            nanos(record.solve),
+            count(|memory| memory.allocations),
+            count(|memory| memory.bytes),
+            count(|memory| memory.peak),
+            record.error.clone().unwrap_or_default(),
+        ];
+
+        let fields = fields.map(csv_field);
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> Record {
+        Record {
+            year: 2023,
+            day: 6,
+            part: 1,
+            input: "input.txt".to_string(),
+            answer: Some(Answer::Unsigned(288)),
+            expected: Some(Expected::Number(288)),
+            status: Status::Correct,
+            parse: Some(Duration::from_nanos(1500)),
+            solve: Some(Duration::from_nanos(20)),
+            memory: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn writes_json_records() {
+        assert_eq!(
+            json(&[record()]),
+            "[\n  {\"year\":2023,\"day\":6,\"part\":1,\"input\":\"input.txt\",\"answer\":288,\
+             \"expected\":288,\"status\":\"correct\",\"parse_ns\":1500,\"solve_ns\":20,\
+             \"error\":null}\n]"
+        );
+        assert_eq!(json(&[]), "[]");
+
+        let counted = Record {
+            memory: Some(Usage {
+                allocations: 2,
+                bytes: 64,
+                peak: 32,
+            }),
+            ..record()
+        };
+        assert!(json(std::slice::from_ref(&counted)).contains(
+            "\"solve_ns\":20,\"allocations\":2,\"allocated_bytes\":64,\"peak_bytes\":32,\"error\":null"
+        ));
+        assert!(csv(&[counted]).ends_with(",1500,20,2,64,32,\n"));
+    }
+
+    #[test]
+    fn writes_csv_records_with_quoting() {
+        let failed = Record {
+            answer: None,
+            expected: None,
+            status: Status::Error,
+            parse: None,
+            solve: None,
+            error: Some("line 1, column 2: expected \"a\", found \"b\"".to_string()),
+            ..record()
+        };
+
+        let lines = csv(&[record(), failed]);
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1], "2023,6,1,input.txt,288,288,correct,1500,20,,,,");
+        assert_eq!(
+            lines[2],
+            "2023,6,1,input.txt,,,error,,,,,,\"line 1, column 2: expected \"\"a\"\", found \"\"b\"\"\""
+        );
+    }
+    #[test]
+    fn writes_parts_with_no_answer_as_errors() {
+        let failed = Record {
+            answer: Some(Answer::failed("no path")),
+            expected: None,
+            status: Status::Error,
+            error: Some("no path".to_string()),
+            ..record()
+        };
+
+        assert!(json(std::slice::from_ref(&failed)).contains("\"answer\":null"));
+        assert!(csv(std::slice::from_ref(&failed))
+            .ends_with("\n2023,6,1,input.txt,,,error,1500,20,,,,no path\n"));
+
+        let source = Source::File(std::env::temp_dir().join("aoc-no-answer").join("input.txt"));
+        assert_eq!(accept(&source, &[failed]).unwrap().1, Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,183 @@
+//! `aoc watch`: rebuilds and re-runs one day every time its source or input changes.
+//!
+//! Only the day's own crate is rebuilt, which is much quicker than rebuilding the runner
+//! with every day in it. Its answers are read back from what it prints and checked against
+//! the known answers.
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{self, BufRead, BufReader, IsTerminal, Write},
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use utils::{input::Source, run};
+
+use crate::{
+    days::Day,
+    report::{self, Status},
+    ROOT,
+};
+
+/// How often the watched files are checked for changes.
+const POLL: Duration = Duration::from_millis(250);
+
+/// The folder holding a day's crate, which is `qN` or `dayN` depending on the year.
+fn crate_dir(day: &Day) -> Option<PathBuf> {
+    let year = Path::new(ROOT).join(format!("advent-{}", day.year));
+    ["q", "day"]
+        .iter()
+        .map(|prefix| year.join(format!("{}{}", prefix, day.day)))
+        .find(|dir| dir.join("Cargo.toml").is_file())
+}
+
+/// Every file under `path`, or `path` itself if it is a file, with when it was last changed.
+/// Files that have gone missing are left out, so deleting one counts as a change too.
+fn modified(path: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            modified(&entry.path(), times);
+        }
+    } else if let Ok(time) = metadata.modified() {
+        times.insert(path.to_path_buf(), time);
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    for path in paths {
+        modified(path, &mut times);
+    }
+    times
+}
+
+/// Builds and runs the day's crate on `input`, printing its output with each answer
+/// checked against the known answers.
+fn build_and_run(package: &str, input: &Path, part: Option<usize>) {
+    if io::stdout().is_terminal() {
+        // Clear the screen so only the latest run is shown.
+        print!("\x1b[2J\x1b[H");
+    }
+    println!("Building {}...", package);
+    let _ = io::stdout().flush();
+
+    // Cargo's own messages and any compile errors go straight to stderr.
+    let cargo = env::var_os("CARGO").unwrap_or("cargo".into());
+    let child = Command::new(cargo)
+        .args(["run", "--quiet", "--release", "--manifest-path"])
+        .arg(Path::new(ROOT).join("Cargo.toml"))
+        .args(["--package", package, "--"])
+        .arg(input)
+        .stdout(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            println!("Could not start cargo: {}", err);
+            return;
+        }
+    };
+
+    let expected = report::expected_for(&Source::File(input.to_path_buf()));
+    if let Err(err) = &expected {
+        println!(
+            "Could not read the answers for {}: {}",
+            input.display(),
+            err
+        );
+    }
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        let Some((p, answer, _)) = run::read_part_line(&line) else {
+            println!("{}", line);
+            continue;
+        };
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+
+        let expected = expected.as_ref().ok().and_then(|parts| parts.part(p));
+        let status = match expected {
+            // How a part with no answer prints, from `Answer::Failed`.
+            _ if answer.starts_with("no answer, ") => Status::Error,
+            Some(expected) if expected.to_string() == answer => Status::Correct,
+            Some(_) => Status::Wrong,
+            None => Status::Unknown,
+        };
+        println!("{:<48} | {}", line, report::describe(status, expected));
+    }
+
+    match child.wait() {
+        Ok(status) if status.success() => (),
+        Ok(_) => println!("{} failed", package),
+        Err(err) => println!("Could not run {}: {}", package, err),
+    }
+    println!("\nWatching for changes, press Ctrl-C to stop.");
+}
+
+/// Runs `day` now and again after every change to its source, the shared `utils` crate,
+/// its input or the input's known answers. Only returns if the day can't be watched.
+pub fn watch(day: &Day, source: &Source, part: Option<usize>) -> ExitCode {
+    let Source::File(input) = source else {
+        eprintln!("watch needs an input file, not stdin");
+        return ExitCode::from(2);
+    };
+    let Some(dir) = crate_dir(day) else {
+        eprintln!("Could not find the crate for {} day {}", day.year, day.day);
+        return ExitCode::FAILURE;
+    };
+
+    let package = format!(
+        "advent-{}-{}",
+        day.year,
+        dir.file_name().unwrap_or_default().to_string_lossy()
+    );
+    let watched = [
+        dir.join("src"),
+        dir.join("Cargo.toml"),
+        input.clone(),
+        report::answers_path(input),
+        Path::new(ROOT).join("utils/src"),
+    ];
+
+    let mut last = snapshot(&watched);
+    build_and_run(&package, input, part);
+
+    loop {
+        thread::sleep(POLL);
+        let now = snapshot(&watched);
+        if now == last {
+            continue;
+        }
+
+        // Editors often write a file in several steps, so wait for it to settle.
+        thread::sleep(POLL);
+        last = snapshot(&watched);
+        build_and_run(&package, input, part);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn finds_each_days_crate() {
+        for day in DAYS {
+            let dir = crate_dir(day).unwrap();
+            assert!(dir.join("src/main.rs").is_file(), "{}", dir.display());
+        }
+
+        let dir = crate_dir(DAYS.iter().find(|d| d.year == 2022).unwrap()).unwrap();
+        assert_eq!(dir.file_name().unwrap(), "day1");
+    }
+}
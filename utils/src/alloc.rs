@@ -0,0 +1,226 @@
+//! Counting what each part allocates, to see memory use next to time.
+//!
+//! Building with the `count-allocs` feature installs a global allocator that counts every
+//! allocation made on each thread, for example:
+//!
+//! ```text
+//! cargo run --release -p aoc --features count-allocs -- 2023 23
+//! cargo run --release -p advent-2023-q23 --features utils/count-allocs
+//! ```
+//!
+//! Without the feature, [`measure`] just runs the code it is given and reports nothing.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// What some code allocated on its own thread.
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// How many times memory was allocated or reallocated.
+    pub allocations: u64,
+    /// The total size of every allocation, whether or not it was freed again.
+    #[serde(rename = "allocated_bytes")]
+    pub bytes: u64,
+    /// The most memory held at once beyond what was already held at the start.
+    #[serde(rename = "peak_bytes")]
+    pub peak: u64,
+}
+
+/// A number of bytes in the largest unit that keeps it at or above 1.
+fn bytes(n: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if n < 1024 {
+        return format!("{n} B");
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+impl Usage {
+    pub fn bytes(&self) -> String {
+        bytes(self.bytes)
+    }
+
+    pub fn peak(&self) -> String {
+        bytes(self.peak)
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            self.bytes(),
+            self.peak()
+        )
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread when the `count-allocs` feature is
+/// on.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        let (result, usage) = counting::measure(f);
+        (result, Some(usage))
+    }
+
+    #[cfg(not(feature = "count-allocs"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Usage;
+
+    /// The running totals for one thread. Memory freed on a different thread from the one
+    /// that allocated it can leave `live` below zero.
+    struct Counts {
+        allocations: Cell<u64>,
+        bytes: Cell<u64>,
+        live: Cell<i64>,
+        peak: Cell<i64>,
+    }
+
+    thread_local! {
+        // A const initialiser and no destructor, so using it never allocates.
+        static COUNTS: Counts = const {
+            Counts {
+                allocations: Cell::new(0),
+                bytes: Cell::new(0),
+                live: Cell::new(0),
+                peak: Cell::new(0),
+            }
+        };
+    }
+
+    fn allocated(size: usize, freed: usize) {
+        // The counts are gone while a thread is shutting down, so those are left out.
+        let _ = COUNTS.try_with(|counts| {
+            counts.allocations.set(counts.allocations.get() + 1);
+            counts.bytes.set(counts.bytes.get() + size as u64);
+            let live = counts.live.get() + size as i64 - freed as i64;
+            counts.live.set(live);
+            counts.peak.set(counts.peak.get().max(live));
+        });
+    }
+
+    fn freed(size: usize) {
+        let _ = COUNTS.try_with(|counts| counts.live.set(counts.live.get() - size as i64));
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                allocated(new_size, layout.size());
+            }
+            new
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+        let (allocations, bytes, live, peak) = COUNTS.with(|counts| {
+            (
+                counts.allocations.replace(0),
+                counts.bytes.replace(0),
+                counts.live.get(),
+                counts.peak.replace(counts.live.get()),
+            )
+        });
+
+        let result = f();
+
+        let usage = COUNTS.with(|counts| {
+            let usage = Usage {
+                allocations: counts.allocations.get(),
+                bytes: counts.bytes.get(),
+                peak: (counts.peak.get() - live).max(0) as u64,
+            };
+            // Put back the totals from before, so an outer `measure` still sees everything.
+            counts.allocations.set(allocations + usage.allocations);
+            counts.bytes.set(bytes + usage.bytes);
+            counts.peak.set(peak.max(counts.peak.get()));
+            usage
+        });
+
+        (result, usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prints_sizes_in_binary_units() {
+        let usage = Usage {
+            allocations: 3,
+            bytes: 1536,
+            peak: 5 * 1024 * 1024,
+        };
+        assert_eq!(
+            usage.to_string(),
+            "3 allocs, 1.5 KiB allocated, 5.0 MiB peak"
+        );
+        assert_eq!(bytes(1023), "1023 B");
+    }
+
+    #[cfg(feature = "count-allocs")]
+    #[test]
+    fn counts_allocations_and_peak() {
+        let (_, usage) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            second.len()
+        });
+
+        let usage = usage.unwrap();
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.bytes, 1600);
+        assert_eq!(usage.peak, 1000);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    /// Why a part has no answer for this input. It is reported as an error, never checked
+    /// against or recorded as a known answer.
+    Failed(String),
+}
+
+impl Answer {
+    /// A part with no answer, for the reason given.
+    pub fn failed(why: impl Into<String>) -> Self {
+        Answer::Failed(why.into())
+    }
+
+    /// Why there is no answer, if there is none.
+    pub fn failure(&self) -> Option<&str> {
+        match self {
+            Answer::Failed(why) => Some(why),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Forward to the inner value so width and alignment flags still apply.
+        match self {
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+            Answer::Failed(why) => format!("no answer, {why}").fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident as $inner:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $inner)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed as i64: i32, i64);
+impl_from!(Unsigned as u64: u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Numbers are written as numbers and text as strings, so machine readable output keeps
+/// the answer's type. A part with no answer is written as nothing at all.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(n) => serializer.serialize_i64(*n),
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Failed(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
@@ -0,0 +1,139 @@
+//! Timing a piece of code over many runs, for comparing solutions without the noise of a
+//! single `Instant::now()` measurement.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How long to spend measuring one piece of code.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Runs before measuring starts, to warm caches and let the CPU clock up.
+    pub warmup: Duration,
+    /// Roughly how long to spend taking samples.
+    pub measure: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+    /// Fast code is run repeatedly within each sample until it takes at least this long,
+    /// so that the timer's own overhead does not dominate.
+    pub min_sample_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: Duration::from_millis(200),
+            measure: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 100,
+            min_sample_time: Duration::from_micros(100),
+        }
+    }
+}
+
+/// The time taken by one run, summarised over every sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub median: Duration,
+    /// Median absolute deviation from the median.
+    pub spread: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises a set of per-run times. Panics if `times` is empty.
+    pub fn new(mut times: Vec<Duration>) -> Self {
+        assert!(!times.is_empty(), "no samples to summarise");
+
+        times.sort_unstable();
+        let median = median(&times);
+
+        let mut deviations: Vec<Duration> = times.iter().map(|t| t.abs_diff(median)).collect();
+        deviations.sort_unstable();
+
+        Stats {
+            samples: times.len(),
+            median,
+            spread: self::median(&deviations),
+            min: times[0],
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Times `f`, warming up first and then taking samples as set by `config`.
+pub fn bench<T>(config: &Config, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut warmup_runs = 0u32;
+    while warmup_runs == 0 || start.elapsed() < config.warmup {
+        black_box(f());
+        warmup_runs += 1;
+    }
+    let estimate = start.elapsed() / warmup_runs;
+
+    let batch = if estimate.is_zero() {
+        1000
+    } else {
+        (config.min_sample_time.as_nanos() / estimate.as_nanos()).clamp(1, 1_000_000) as u32
+    };
+    let samples = (config.measure.as_nanos() / (estimate * batch).as_nanos().max(1))
+        .clamp(config.min_samples as u128, config.max_samples as u128) as usize;
+
+    let times = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..batch {
+                black_box(f());
+            }
+            start.elapsed() / batch
+        })
+        .collect();
+
+    Stats::new(times)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3), ms(100), ms(2)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.spread, ms(2));
+        assert_eq!((stats.min, stats.max, stats.samples), (ms(1), ms(100), 5));
+
+        assert_eq!(
+            Stats::new(vec![ms(1), ms(2)]).median,
+            Duration::from_micros(1500)
+        );
+    }
+
+    #[test]
+    fn takes_at_least_the_minimum_samples() {
+        let config = Config {
+            warmup: Duration::ZERO,
+            measure: Duration::ZERO,
+            ..Config::default()
+        };
+        let stats = bench(&config, || std::thread::sleep(ms(1)));
+        assert_eq!(stats.samples, config.min_samples);
+        assert!(stats.min >= ms(1));
+    }
+}
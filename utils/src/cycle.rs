@@ -0,0 +1,204 @@
+//! Finding where a process run over and over starts repeating itself, so the state after a
+//! huge number of steps can be worked out without taking them all.
+//!
+//! States are told apart by a key. The state itself works, but for big states a
+//! [`fingerprint`] saves keeping a copy of every one seen.
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::math;
+
+/// Where the states of a process repeat: the state after `start + period` steps is the
+/// same as after `start` steps, and so on from there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step that is in the same state as step `n`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// A 64 bit hash of `state`, to use as a key when keeping every state seen would take too
+/// much memory. Two different states could in theory share one, but it is vanishingly
+/// unlikely.
+pub fn fingerprint<S: Hash + ?Sized>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Steps `state` until its `key` is one seen before, leaving it as it was after
+/// `start + period` steps. Never returns if the states never repeat.
+pub fn find_by_key<S, K: Eq + Hash>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut n = 0;
+    loop {
+        if let Some(start) = seen.insert(key(state), n) {
+            return Cycle {
+                start,
+                period: n - start,
+            };
+        }
+        step(state);
+        n += 1;
+    }
+}
+
+/// Like [`find_by_key`], keeping a copy of each state as its key.
+pub fn find<S: Clone + Eq + Hash>(state: &mut S, step: impl FnMut(&mut S)) -> Cycle {
+    find_by_key(state, step, S::clone)
+}
+
+/// The state after stepping `state` `n` times, going round any cycle found on the way just
+/// once.
+pub fn nth_by_key<S, K: Eq + Hash>(
+    mut state: S,
+    n: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> S {
+    let mut seen = HashMap::new();
+    for taken in 0..n {
+        if let Some(start) = seen.insert(key(&state), taken) {
+            // Going round the cycle a whole number of times changes nothing.
+            for _ in 0..(n - taken) % (taken - start) {
+                step(&mut state);
+            }
+            return state;
+        }
+        step(&mut state);
+    }
+    state
+}
+
+/// Like [`nth_by_key`], keeping a copy of each state as its key.
+pub fn nth<S: Clone + Eq + Hash>(state: S, n: usize, step: impl FnMut(&mut S)) -> S {
+    nth_by_key(state, n, step, S::clone)
+}
+
+/// The first step at which several processes are all at one of their goals, or `None` if
+/// there are no processes or they never line up. Each comes with its [`Cycle`] and the
+/// steps at which it is at a goal, up to the end of its first time round the cycle.
+///
+/// Before every process is in its cycle the steps are checked one by one. After that, each
+/// process is at a goal a whole number of periods after one of the goals in its cycle, so
+/// the answer is the earliest step at which one such goal from every process lines up.
+pub fn first_together(processes: &[(Cycle, Vec<usize>)]) -> Option<usize> {
+    let looping = processes.iter().map(|(cycle, _)| cycle.start).max()?;
+    let together = |step: usize| {
+        processes
+            .iter()
+            .all(|(cycle, goals)| goals.contains(&cycle.equivalent(step)))
+    };
+    if let Some(step) = (0..looping).find(|&step| together(step)) {
+        return Some(step);
+    }
+
+    // Every way of picking one goal in each cycle, with the latest goal picked.
+    let mut picks = vec![(Vec::new(), 0)];
+    for (cycle, goals) in processes {
+        let looped = goals.iter().filter(|&&goal| goal >= cycle.start);
+        picks = picks
+            .iter()
+            .flat_map(|(congruences, latest)| {
+                looped.clone().map(move |&goal| {
+                    let mut congruences: Vec<(i64, i64)> = congruences.clone();
+                    congruences.push((goal as i64, cycle.period as i64));
+                    (congruences, goal.max(*latest))
+                })
+            })
+            .collect();
+    }
+
+    picks
+        .into_iter()
+        .filter_map(|(congruences, latest)| {
+            let (first, period) = math::crt(congruences)?;
+            let (first, period) = (first as usize, period as usize);
+            // Every process has to be in its cycle, so this could be a few periods on.
+            Some(first + latest.saturating_sub(first).div_ceil(period) * period)
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Squares and adds one, modulo 1000, which from 0 settles into a loop after a few steps.
+    fn step(x: &mut u64) {
+        *x = (*x * *x + 1) % 1000;
+    }
+
+    fn brute_force(n: usize) -> u64 {
+        let mut x = 0;
+        for _ in 0..n {
+            step(&mut x);
+        }
+        x
+    }
+
+    #[test]
+    fn finds_where_states_repeat() {
+        let mut x = 0;
+        let cycle = find(&mut x, step);
+        assert_eq!(x, brute_force(cycle.start + cycle.period));
+        assert_eq!(x, brute_force(cycle.start));
+        assert!((0..cycle.start).all(|n| brute_force(n) != x));
+
+        let mut y = 0;
+        assert_eq!(find_by_key(&mut y, step, fingerprint), cycle);
+        assert_eq!(cycle.equivalent(3), 3);
+        assert_eq!(brute_force(cycle.equivalent(500)), brute_force(500));
+    }
+
+    #[test]
+    fn finds_when_cycles_line_up() {
+        let cycle = |start, period| Cycle { start, period };
+        // At goals on steps 3, 7, 11, ... and on steps 2, 7, 12, ...
+        let processes = [(cycle(0, 4), vec![3]), (cycle(2, 5), vec![2])];
+        assert_eq!(first_together(&processes), Some(7));
+
+        // Both at a goal before the second is in its cycle.
+        let processes = [(cycle(0, 2), vec![1]), (cycle(5, 3), vec![1, 6])];
+        assert_eq!(first_together(&processes), Some(1));
+
+        let processes = [(cycle(0, 2), vec![0]), (cycle(0, 4), vec![1, 3])];
+        assert_eq!(first_together(&processes), None);
+        assert_eq!(first_together(&[]), None);
+    }
+
+    #[test]
+    fn jumps_to_any_step() {
+        for n in [0, 1, 2, 10, 57, 500, 1001] {
+            assert_eq!(nth(0, n, step), brute_force(n), "step {n}");
+            assert_eq!(
+                nth_by_key(0, n, step, fingerprint),
+                brute_force(n),
+                "step {n}"
+            );
+        }
+        // Far too many steps to take one at a time.
+        let cycle = find(&mut 0, step);
+        assert_eq!(
+            nth(0, 1_000_000_000_000, step),
+            brute_force(cycle.equivalent(1_000_000_000_000))
+        );
+    }
+}
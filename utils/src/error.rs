@@ -0,0 +1,216 @@
+//! The error every parser returns, pointing at the place in the input it could not read.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::puzzles;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The year and day of the puzzle being parsed, filled in by the runner.
+    pub puzzle: Option<(u16, u8)>,
+    /// Line and column of the problem, both counted from 1, or both 0 when it is not known.
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    /// The text that was there instead, empty at the end of a line or of the input.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            puzzle: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// An error for `token`, which must be a slice of `input`, found where `expected`
+    /// should have been. A `token` from anywhere else gives an error with no place, and
+    /// fails an assertion in debug builds.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).checked_sub(input.as_ptr() as usize);
+        let offset = offset.filter(|&offset| {
+            offset + token.len() <= input.len() && input.is_char_boundary(offset)
+        });
+        debug_assert!(
+            offset.is_some(),
+            "{token:?} should be a slice of {input:?}, starting on a character"
+        );
+
+        match offset {
+            Some(offset) => Self::at_offset(input, offset, token, expected),
+            None => Self::new(0, 0, expected, token),
+        }
+    }
+
+    /// An error for `found`, which starts `offset` bytes into `input`.
+    pub fn at_offset(input: &str, offset: usize, found: &str, expected: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Self::new(line, column, expected, found)
+    }
+
+    /// An error for the end of `line`, a slice of `input`, when something more was
+    /// `expected`.
+    pub fn at_end(input: &str, line: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &line[line.len()..], expected)
+    }
+
+    /// Moves an error found while parsing `part`, a slice of `input`, to where `part` sits
+    /// in `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        if self.line == 0 {
+            return self;
+        }
+        let start = Self::at(input, part, "");
+        let column = if self.line == 1 {
+            start.column + self.column - 1
+        } else {
+            self.column
+        };
+
+        ParseError {
+            line: start.line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    /// Moves an error found while parsing one line on its own to line `line` of the whole
+    /// input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        if self.line == 0 {
+            return self;
+        }
+        self.line += line - 1;
+        self
+    }
+
+    /// Replaces what was expected with a more specific description.
+    pub fn expecting(mut self, expected: impl Into<String>) -> Self {
+        self.expected = expected.into();
+        self
+    }
+
+    pub fn in_puzzle(mut self, year: u16, day: u8) -> Self {
+        self.puzzle = Some((year, day));
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((year, day)) = self.puzzle {
+            write!(f, "{}, ", puzzles::name(year, day))?;
+        }
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "expected {}, found ", self.expected)?;
+
+        if self.found.is_empty() {
+            write!(f, "nothing")?;
+        } else {
+            write!(f, "{:?}", self.found)?;
+        }
+
+        let puzzle = self.puzzle.and_then(|(year, day)| puzzles::find(year, day));
+        match puzzle {
+            Some(puzzle) => write!(f, "; the input should be {}", puzzle.input),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a `T`, pointing at it if it is not one.
+pub fn parse_at<T: FromStr>(
+    input: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens_in_the_input() {
+        let input = "12 34\nab cd\n";
+        let token = &input[9..11];
+        let err = ParseError::at(input, token, "a number");
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, "cd"));
+
+        let err = err.in_puzzle(2023, 4);
+        assert_eq!(
+            err.to_string(),
+            "2023 Day 04: Scratchcards, line 2, column 4: expected a number, found \"cd\"; \
+             the input should be cards like `Card 1: 41 48 83 | 83 86 6 31`"
+        );
+        assert_eq!(
+            err.in_puzzle(2015, 1).to_string(),
+            "2015 Day 01, line 2, column 4: expected a number, found \"cd\""
+        );
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_line() {
+        let input = "a b\nc";
+        let line = input.lines().next().unwrap();
+        let err = ParseError::at_end(input, line, "a third field");
+        assert_eq!((err.line, err.column), (1, 4));
+        assert!(err.to_string().ends_with("found nothing"));
+    }
+
+    #[test]
+    fn moves_errors_from_part_of_the_input() {
+        let input = "ab\n\ncd\nef";
+        let part = &input[4..];
+        let err = ParseError::new(2, 2, "g", "f").within(input, part);
+        assert_eq!((err.line, err.column), (4, 2));
+
+        let part = &input[5..];
+        let err = ParseError::new(1, 1, "c", "d").within(input, part);
+        assert_eq!((err.line, err.column), (3, 2));
+
+        let err = ParseError::new(1, 5, "e", "f").on_line(7);
+        assert_eq!((err.line, err.column), (7, 5));
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "should be a slice"))]
+    fn gives_no_place_for_tokens_from_elsewhere() {
+        let err = ParseError::at("12 34", "cd", "a number");
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.within("ab\ncd", "cd").on_line(3).line, 0);
+        assert_eq!(
+            ParseError::at("12 34", "cd", "a number").to_string(),
+            "expected a number, found \"cd\""
+        );
+    }
+
+    #[test]
+    fn parses_tokens_or_points_at_them() {
+        let input = "7 x";
+        assert_eq!(parse_at::<u8>(input, &input[..1], "a number"), Ok(7));
+
+        let err = parse_at::<u8>(input, &input[2..], "a number").unwrap_err();
+        assert_eq!(err.column, 3);
+    }
+}
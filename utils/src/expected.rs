@@ -0,0 +1,298 @@
+//! Known answers for each day, kept next to its inputs in `inputs/<year>/dayNN/answers.toml`:
+//!
+//! ```toml
+//! [inputs]
+//! "input.txt" = { part1 = 142, part2 = 281 }
+//!
+//! [[example]]
+//! part1 = 8
+//! input = '''
+//! Game 1: 3 blue, 4 red
+//! '''
+//! ```
+//!
+//! Inputs name files in the same folder, and examples hold the puzzle's worked examples
+//! inline. A part without an answer is not checked, which is how examples that only apply
+//! to one part, or that need settings the real puzzle does not use, are left out.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{input, parse_input, puzzles, Answer, Solution};
+
+/// An answer as written in `answers.toml`, either a number or text.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl Expected {
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (Expected::Number(n), Answer::Signed(a)) => n == a,
+            (Expected::Number(n), Answer::Unsigned(a)) => u64::try_from(*n) == Ok(*a),
+            (Expected::Text(s), Answer::Text(a)) => s == a,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(n) => n.fmt(f),
+            Expected::Text(s) => s.fmt(f),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Parts {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl Parts {
+    /// The expected answer for `part`, which is 1 or 2.
+    pub fn part(&self, part: usize) -> Option<&Expected> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub input: String,
+    #[serde(flatten)]
+    pub answers: Parts,
+}
+
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    /// Answers for the input files in the day's folder, by file name.
+    #[serde(default)]
+    pub inputs: BTreeMap<String, Parts>,
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        input::dir(year, day).join("answers.toml")
+    }
+
+    /// Reads the answers for a day, or none if it has no `answers.toml`.
+    pub fn load(year: u16, day: u8) -> Result<Self, Box<dyn Error>> {
+        Self::load_from(&Self::path(year, day))
+    }
+
+    /// Reads the answers in the file at `path`, or none if there is no such file.
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let text = fs::read_to_string(path)?;
+        toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+}
+
+fn toml_value(answer: &Answer) -> Result<toml_edit::Value, Box<dyn Error>> {
+    Ok(match answer {
+        Answer::Signed(n) => (*n).into(),
+        Answer::Unsigned(n) => i64::try_from(*n)
+            .map_err(|_| format!("{n} is too large to store in TOML"))?
+            .into(),
+        Answer::Text(s) => s.as_str().into(),
+        Answer::Failed(why) => return Err(format!("there is no answer, {why}").into()),
+    })
+}
+
+/// Records `answers`, each a part number and its answer, for the input file named `input` in
+/// the `answers.toml` at `path`, creating the file if needed.
+///
+/// Parts that already have an answer are left alone, as is the rest of the file, comments
+/// included. Returns the parts that were added.
+pub fn accept(
+    path: &Path,
+    input: &str,
+    answers: &[(usize, &Answer)],
+) -> Result<Vec<usize>, Box<dyn Error>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err.into()),
+    };
+    let mut doc = text.parse::<toml_edit::DocumentMut>()?;
+
+    let inputs = doc
+        .entry("inputs")
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .ok_or("`inputs` is not a table")?;
+    let parts = inputs
+        .entry(input)
+        .or_insert(toml_edit::value(toml_edit::InlineTable::new()))
+        .as_inline_table_mut()
+        .ok_or_else(|| format!("the answers for `{input}` are not an inline table"))?;
+
+    let mut added = Vec::new();
+    for &(part, answer) in answers {
+        let key = format!("part{part}");
+        if !parts.contains_key(&key) {
+            parts.insert(&key, toml_value(answer)?);
+            added.push(part);
+        }
+    }
+
+    if !added.is_empty() {
+        parts.fmt();
+        fs::write(path, doc.to_string())?;
+    }
+    Ok(added)
+}
+
+/// Solves every example and input listed in the day's `answers.toml`, panicking with every
+/// answer that does not match. Meant to be called from each day's tests.
+pub fn check<S: Solution>() {
+    check_with(
+        S::YEAR,
+        S::DAY,
+        |text| Ok(parse_input::<S>(text)?),
+        [S::part1, S::part2],
+    );
+}
+
+/// Reads each example and input for a day with `read`, then answers the parts that have
+/// known answers with `solvers`, panicking with every answer that does not match.
+pub(crate) fn check_with<T>(
+    year: u16,
+    day: u8,
+    read: impl Fn(&str) -> Result<T, Box<dyn Error>>,
+    solvers: [fn(&T) -> Answer; 2],
+) {
+    let answers = Answers::load(year, day).unwrap();
+    assert!(
+        !answers.inputs.is_empty() || !answers.examples.is_empty(),
+        "{} has no answers",
+        Answers::path(year, day).display()
+    );
+
+    let mut failures = Vec::new();
+
+    let examples = answers.examples.iter().enumerate().map(|(i, example)| {
+        let name = format!("example {}", i + 1);
+        (name, Ok(input::normalise(&example.input)), &example.answers)
+    });
+    let inputs = answers.inputs.iter().map(|(file, parts)| {
+        let path = input::dir(year, day).join(file);
+        let text = input::Source::File(path).read();
+        (file.clone(), text, parts)
+    });
+
+    for (name, text, parts) in examples.chain(inputs) {
+        let parsed = match text.map_err(Box::<dyn Error>::from).and_then(|t| read(&t)) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                failures.push(format!("{name}: {err}"));
+                continue;
+            }
+        };
+
+        for (i, solve) in solvers.iter().enumerate() {
+            if !puzzles::solves(year, day, i + 1) {
+                continue;
+            }
+            if let Some(expected) = parts.part(i + 1) {
+                let answer = solve(&parsed);
+                if !expected.matches(&answer) {
+                    failures.push(format!(
+                        "{name} part {}: expected {expected}, got {answer}",
+                        i + 1
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}:\n{}",
+        puzzles::name(year, day),
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn reads_inputs_and_examples() {
+        let answers: Answers = toml::from_str(
+            r#"
+            [inputs]
+            "input.txt" = { part1 = 3, part2 = "abc" }
+
+            [[example]]
+            part2 = 7
+            input = '''
+            x
+            '''
+            "#,
+        )
+        .unwrap();
+
+        let parts = &answers.inputs["input.txt"];
+        assert!(parts.part(1).unwrap().matches(&Answer::Unsigned(3)));
+        assert!(parts.part(2).unwrap().matches(&"abc".into()));
+        assert_eq!(answers.examples[0].answers.part1, None);
+        assert!(!Expected::Number(-1).matches(&Answer::Unsigned(u64::MAX)));
+    }
+
+    #[test]
+    fn accepts_only_missing_answers() {
+        let path = env::temp_dir().join(format!("aoc-accept-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "# Kept\n[inputs]\n\"input.txt\" = { part1 = 3 }\n\n[[example]]\npart1 = 1\ninput = 'x'\n",
+        )
+        .unwrap();
+
+        let (four, five, text) = (Answer::Unsigned(4), Answer::Unsigned(5), "abc".into());
+        let added = accept(&path, "input.txt", &[(1, &four), (2, &five)]).unwrap();
+        assert_eq!(added, [2]);
+        let added = accept(&path, "other.txt", &[(1, &text)]).unwrap();
+        assert_eq!(added, [1]);
+
+        let written = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(written.starts_with("# Kept\n"));
+
+        let answers: Answers = toml::from_str(&written).unwrap();
+        let parts = &answers.inputs["input.txt"];
+        assert_eq!(parts.part1, Some(Expected::Number(3)));
+        assert_eq!(parts.part2, Some(Expected::Number(5)));
+        assert_eq!(
+            answers.inputs["other.txt"].part1,
+            Some(Expected::Text("abc".to_string()))
+        );
+        assert_eq!(answers.examples.len(), 1);
+    }
+}
@@ -0,0 +1,327 @@
+//! Points and vectors on a plane or in space, the four directions on a grid, and boxes
+//! bounding a set of points.
+//!
+//! Points are unsigned, for indexing grids and measuring from an origin in a corner.
+//! Vectors are signed, for moves and offsets that can point either way. `y` grows
+//! downwards, the way grids are read.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Defines a point or vector type with `$t` coordinates named `$field`, which measures
+/// distances as `$distance`.
+macro_rules! coordinates {
+    ($(#[$doc:meta])* $name:ident($t:ty, $distance:ty) { $($field:ident),+ }) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord, Default)]
+        pub struct $name {
+            $(pub $field: $t),+
+        }
+
+        impl $name {
+            pub const fn new($($field: $t),+) -> Self {
+                $name { $($field),+ }
+            }
+
+            /// The sum of the distances along each axis to `other`.
+            pub fn manhattan(self, other: Self) -> $distance {
+                0 $(+ self.$field.abs_diff(other.$field))+
+            }
+        }
+
+        impl Corner for $name {
+            fn lower(self, other: Self) -> Self {
+                $name { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            fn upper(self, other: Self) -> Self {
+                $name { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            fn within(self, other: Self) -> bool {
+                $(self.$field <= other.$field)&&+
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<$t> for $name {
+            type Output = Self;
+
+            fn mul(self, scale: $t) -> Self {
+                $name { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coordinates = [$(self.$field.to_string()),+];
+                write!(f, "({})", coordinates.join(", "))
+            }
+        }
+
+        impl Bounds<$name> {
+            /// How many whole positions the box covers along each axis.
+            pub fn size(&self) -> $name {
+                $name { $($field: self.max.$field - self.min.$field + 1),+ }
+            }
+        }
+    };
+}
+
+/// Defines the conversions between a point type and its vector type, which has the same
+/// coordinates with a sign.
+macro_rules! signed {
+    ($point:ident, $vector:ident { $($field:ident),+ }) => {
+        impl $point {
+            /// The point `vector` away, unless that is below zero along some axis.
+            pub fn checked_add(self, vector: $vector) -> Option<Self> {
+                Some($point {
+                    $($field: self.$field.checked_add_signed(vector.$field.try_into().ok()?)?),+
+                })
+            }
+        }
+
+        impl $vector {
+            /// The distance from the origin, along each axis in turn.
+            pub fn length(self) -> u64 {
+                self.manhattan($vector::default())
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+
+        impl From<$point> for $vector {
+            fn from(point: $point) -> Self {
+                $vector { $($field: point.$field as i64),+ }
+            }
+        }
+
+        impl TryFrom<$vector> for $point {
+            type Error = std::num::TryFromIntError;
+
+            fn try_from(vector: $vector) -> Result<Self, Self::Error> {
+                Ok($point { $($field: vector.$field.try_into()?),+ })
+            }
+        }
+    };
+}
+
+coordinates! {
+    /// A position on a plane, such as a tile of a [`crate::grid::Grid`].
+    Point(usize, usize) { x, y }
+}
+
+coordinates! {
+    /// A move or offset on a plane.
+    Vector(i64, u64) { x, y }
+}
+
+coordinates! {
+    /// A position in space.
+    Point3(usize, usize) { x, y, z }
+}
+
+coordinates! {
+    /// A move or offset in space.
+    Vector3(i64, u64) { x, y, z }
+}
+
+signed!(Point, Vector { x, y });
+signed!(Point3, Vector3 { x, y, z });
+
+/// What a [`Bounds`] needs of the points at its corners.
+pub trait Corner: Copy {
+    /// The smaller of each coordinate.
+    fn lower(self, other: Self) -> Self;
+
+    /// The larger of each coordinate.
+    fn upper(self, other: Self) -> Self;
+
+    /// Whether no coordinate is larger than in `other`.
+    fn within(self, other: Self) -> bool;
+}
+
+/// The smallest box holding a set of points, corners included.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Corner> Bounds<P> {
+    /// The box with `a` and `b` as opposite corners.
+    pub fn new(a: P, b: P) -> Self {
+        Bounds {
+            min: a.lower(b),
+            max: a.upper(b),
+        }
+    }
+
+    /// The smallest box holding every one of `points`, if there are any.
+    pub fn of(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Bounds::new(first, first);
+        for point in points {
+            bounds.include(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the box just enough to hold `point`.
+    pub fn include(&mut self, point: P) {
+        self.min = self.min.lower(point);
+        self.max = self.max.upper(point);
+    }
+
+    /// Whether `point` is inside the box or on its edge.
+    pub fn contains(&self, point: P) -> bool {
+        self.min.within(point) && point.within(self.max)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// One step this way, with north towards smaller `y`.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(0, -1),
+            Direction::East => Vector::new(1, 0),
+            Direction::South => Vector::new(0, 1),
+            Direction::West => Vector::new(-1, 0),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_arithmetic_on_each_coordinate() {
+        let a = Vector3::new(1, -2, 3);
+        let b = Vector3::new(-4, 5, 6);
+        assert_eq!(a + b, Vector3::new(-3, 3, 9));
+        assert_eq!(a - b, Vector3::new(5, -7, -3));
+        assert_eq!(-a * 2, Vector3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(b), 5 + 7 + 3);
+        assert_eq!(b.length(), 15);
+
+        let mut p = Point::new(3, 4);
+        p -= Point::new(1, 1);
+        assert_eq!(p, Point::new(2, 3));
+        assert_eq!(p.manhattan(Point::new(0, 7)), 6);
+        assert_eq!(p.to_string(), "(2, 3)");
+    }
+
+    #[test]
+    fn moves_points_by_vectors_without_going_below_zero() {
+        let p = Point::new(1, 0);
+        assert_eq!(p.checked_add(Vector::new(-1, 2)), Some(Point::new(0, 2)));
+        assert_eq!(p.checked_add(Direction::North.vector()), None);
+        assert_eq!(Point::try_from(Vector::from(p) * 3), Ok(Point::new(3, 0)));
+        assert!(Point3::try_from(Vector3::new(0, -1, 0)).is_err());
+    }
+
+    #[test]
+    fn turns_and_reverses_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+        }
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn bounds_every_point() {
+        let points = [Vector::new(2, -1), Vector::new(-3, 4), Vector::new(0, 0)];
+        let bounds = Bounds::of(points).unwrap();
+        assert_eq!(bounds.min, Vector::new(-3, -1));
+        assert_eq!(bounds.max, Vector::new(2, 4));
+        assert_eq!(bounds.size(), Vector::new(6, 6));
+        assert!(bounds.contains(Vector::new(-3, 4)));
+        assert!(!bounds.contains(Vector::new(3, 0)));
+
+        let brick = Bounds::new(Point3::new(2, 0, 5), Point3::new(0, 0, 1));
+        assert_eq!(brick.min, Point3::new(0, 0, 1));
+        assert_eq!(Bounds::<Point>::of([]), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! Searches over graphs given as a start and a function from each state to the states that
+//! follow it, so any type that can be hashed can be a node: a tile, a tile and a heading, a
+//! whole board.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    iter,
+    ops::Add,
+};
+
+/// Every state reachable from `start`, each with the fewest moves needed to reach it, in
+/// the order they are found.
+pub fn bfs<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> impl Iterator<Item = (S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    iter::from_fn(move || {
+        let (state, distance) = queue.pop_front()?;
+        for next in neighbours(&state) {
+            if !seen.contains_key(&next) {
+                seen.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+        Some((state, distance))
+    })
+}
+
+/// A state waiting to be searched, ordered so the one that looks cheapest is popped first.
+struct Entry<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+/// Every state reachable from any of `starts`, each with the least cost of reaching it.
+/// `successors` gives the states one move away and what each move costs.
+///
+/// States come out in order of their cost plus `heuristic`, which guesses the cost still to
+/// go without ever guessing too high. Taking the first state that is a goal finds the
+/// cheapest way there.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> impl Iterator<Item = (S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut least = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for state in starts {
+        least.insert(state.clone(), C::default());
+        queue.push(Entry {
+            estimate: heuristic(&state),
+            cost: C::default(),
+            state,
+        });
+    }
+
+    iter::from_fn(move || {
+        while let Some(Entry { cost, state, .. }) = queue.pop() {
+            // A cheaper way here was found after this one was queued.
+            if least[&state] < cost {
+                continue;
+            }
+
+            for (next, step) in successors(&state) {
+                let cost = cost + step;
+                if least.get(&next).is_none_or(|&least| cost < least) {
+                    least.insert(next.clone(), cost);
+                    queue.push(Entry {
+                        estimate: cost + heuristic(&next),
+                        cost,
+                        state: next,
+                    });
+                }
+            }
+            return Some((state, cost));
+        }
+        None
+    })
+}
+
+/// Like [`astar`] with no guess at the cost to go, so states come out cheapest first.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> impl Iterator<Item = (S, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default())
+}
+
+/// A graph with its nodes numbered in the order they were found, the first being the start.
+pub struct Compressed<S> {
+    pub nodes: Vec<S>,
+    /// The nodes each node leads to, with the number of moves it takes.
+    pub edges: Vec<Vec<(usize, usize)>>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Eq + Hash> Compressed<S> {
+    /// The number given to `node`, if it is one.
+    pub fn index(&self, node: &S) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+}
+
+impl<S> Compressed<S> {
+    /// The most moves it can take to go from `start` to `end` without visiting any node
+    /// twice, if `end` can be reached at all. This tries every way there, so is only quick
+    /// on small graphs.
+    pub fn longest_path(&self, start: usize, end: usize) -> Option<usize> {
+        fn search(
+            edges: &[Vec<(usize, usize)>],
+            node: usize,
+            end: usize,
+            seen: &mut [bool],
+        ) -> Option<usize> {
+            if node == end {
+                return Some(0);
+            }
+
+            seen[node] = true;
+            let mut longest = None;
+            for &(next, moves) in &edges[node] {
+                if !seen[next] {
+                    let rest = search(edges, next, end, seen);
+                    longest = longest.max(rest.map(|rest| rest + moves));
+                }
+            }
+            seen[node] = false;
+            longest
+        }
+
+        search(&self.edges, start, end, &mut vec![false; self.nodes.len()])
+    }
+}
+
+/// The graph reachable from `start` with each corridor, a run of states with no choice of
+/// where to go but onwards, replaced by a single edge between the states at its ends.
+/// Every move is assumed to be one that can be undone, like a step on a map, so a state
+/// is in a corridor when it has exactly one neighbour besides the one it was reached from.
+/// A corridor that loops back to where it started becomes an edge from a node to itself.
+pub fn compress<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> Compressed<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes = Vec::new();
+    let mut index = HashMap::new();
+    let mut ends = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if index.contains_key(&node) {
+            continue;
+        }
+        index.insert(node.clone(), nodes.len());
+
+        let mut corridors = Vec::new();
+        for next in neighbours(&node) {
+            let (mut from, mut to, mut moves) = (node.clone(), next, 1);
+            // A corridor that leads back round to `node` ends there, however it got there.
+            while to != node {
+                let mut onward = neighbours(&to).into_iter().filter(|state| *state != from);
+                let (Some(next), None) = (onward.next(), onward.next()) else {
+                    break;
+                };
+                from = std::mem::replace(&mut to, next);
+                moves += 1;
+            }
+            stack.push(to.clone());
+            corridors.push((to, moves));
+        }
+
+        nodes.push(node);
+        ends.push(corridors);
+    }
+
+    let edges = ends
+        .into_iter()
+        .map(|corridors| {
+            corridors
+                .into_iter()
+                .map(|(end, moves)| (index[&end], moves))
+                .collect()
+        })
+        .collect();
+
+    Compressed {
+        nodes,
+        edges,
+        index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Point};
+
+    const MAZE: &str = "\
+#.#####
+#.....#
+#.###.#
+#.....#
+#####.#";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse(MAZE, |c| Some(c == '.')).unwrap()
+    }
+
+    fn paths(maze: &Grid<bool>, point: Point) -> impl Iterator<Item = Point> + '_ {
+        maze.neighbours(point).filter(|&next| maze[next])
+    }
+
+    #[test]
+    fn finds_fewest_moves_breadth_first() {
+        let maze = maze();
+        let distances =
+            bfs(Point::new(1, 0), |&point| paths(&maze, point)).collect::<HashMap<_, _>>();
+        assert_eq!(distances.len(), 14);
+        assert_eq!(distances[&Point::new(5, 4)], 8);
+        assert_eq!(distances[&Point::new(3, 3)], 5);
+    }
+
+    #[test]
+    fn finds_least_cost_with_or_without_a_heuristic() {
+        let maze = maze();
+        let end = Point::new(5, 4);
+        // Going right costs three times as much as any other move.
+        let moves = |&point: &Point| {
+            paths(&maze, point)
+                .map(move |next| (next, if next.x > point.x { 3 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let cheapest = dijkstra([Point::new(1, 0)], moves)
+            .find(|&(point, _)| point == end)
+            .map(|(_, cost)| cost);
+        assert_eq!(cheapest, Some(16));
+
+        let guided = astar([Point::new(1, 0)], moves, |point| point.manhattan(end))
+            .find(|&(point, _)| point == end)
+            .map(|(_, cost)| cost);
+        assert_eq!(guided, cheapest);
+    }
+
+    #[test]
+    fn compresses_corridors_and_finds_the_longest_path() {
+        let maze = maze();
+        let graph = compress(Point::new(1, 0), |&point| paths(&maze, point));
+        // The start, the two corners where the loop forks and the end.
+        assert_eq!(graph.nodes.len(), 4);
+
+        let start = graph.index(&Point::new(1, 0)).unwrap();
+        let end = graph.index(&Point::new(5, 4)).unwrap();
+        assert_eq!(
+            graph.edges[start],
+            [(graph.index(&Point::new(1, 1)).unwrap(), 1)]
+        );
+        assert_eq!(graph.longest_path(start, end), Some(1 + 6 + 1));
+        assert_eq!(graph.longest_path(end, start), Some(8));
+    }
+
+    #[test]
+    fn compresses_loops_with_no_junction() {
+        let ring = Grid::parse("...\n.#.\n...", |c| Some(c == '.')).unwrap();
+        let graph = compress(Point::new(0, 0), |&point| paths(&ring, point));
+        assert_eq!(graph.nodes, [Point::new(0, 0)]);
+        assert_eq!(graph.edges, [[(0, 8), (0, 8)]]);
+        assert_eq!(graph.longest_path(0, 0), Some(0));
+    }
+}
@@ -0,0 +1,282 @@
+//! A rectangular grid of tiles stored in one `Vec`, row by row, with `(0, 0)` in the top
+//! left corner.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::error::ParseError;
+use crate::geometry::Vector;
+pub use crate::geometry::{Direction, Point};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its tiles, row by row.
+    ///
+    /// Panics if `tiles` does not split into rows of `width`.
+    pub fn from_vec(tiles: Vec<T>, width: usize) -> Self {
+        assert!(
+            width > 0 && tiles.len().is_multiple_of(width),
+            "{} tiles do not make rows of {}",
+            tiles.len(),
+            width
+        );
+
+        Grid {
+            height: tiles.len() / width,
+            tiles,
+            width,
+        }
+    }
+
+    /// Reads one tile per character, one row per line. Characters `tile` does not accept
+    /// are reported as "a tile"; callers can name the tiles with [`ParseError::expecting`].
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut tiles = Vec::with_capacity(input.len());
+        let mut width = None;
+
+        for (y, line) in input.lines().enumerate() {
+            let start = tiles.len();
+            for (x, c) in line.chars().enumerate() {
+                let found = tile(c).ok_or_else(|| ParseError::new(y + 1, x + 1, "a tile", c))?;
+                tiles.push(found);
+            }
+
+            let found = tiles.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        found.min(expected) + 1,
+                        format!("a row {} tiles wide", expected),
+                        format!("{} tiles", found),
+                    ))
+                }
+                Some(_) => (),
+            }
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_vec(tiles, width)),
+            _ => Err(ParseError::new(1, 1, "a grid", "")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.tiles[self.width * point.y + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.tiles[self.width * point.y + point.x])
+    }
+
+    /// The tiles, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.tiles.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.tiles.iter_mut()
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.tiles.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.tiles[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The neighbour of `point` one step in `direction`, if it is inside the grid.
+    pub fn go(&self, point: Point, direction: Direction) -> Option<Point> {
+        let next = point.checked_add(direction.vector())?;
+        self.contains(next).then_some(next)
+    }
+
+    /// The up to four neighbours of `point` that share an edge with it, clockwise from
+    /// north.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.go(point, direction))
+    }
+
+    /// The up to eight neighbours of `point`, including diagonals, row by row.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let next = point.checked_add(Vector::new(dx, dy))?;
+                self.contains(next).then_some(next)
+            })
+    }
+
+    /// A grid of the same shape with `f` applied to every tile.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            tiles: self.tiles.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Self {
+        Grid::from_vec(vec![tile; width * height], width)
+    }
+
+    /// Swaps rows and columns, so the tile at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remap(height, self.width, |x, y| Point::new(y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, width, |x, y| Point::new(width - 1 - y, x))
+    }
+
+    /// Builds a `width` by `height` grid, taking the tile at each `(x, y)` from
+    /// `source(x, y)` in this grid.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Point) -> Self {
+        let tiles = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Grid::from_vec(tiles, width)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        &self.tiles[self.width * point.y + point.x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        &mut self.tiles[self.width * point.y + point.x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn reports_bad_tiles_and_ragged_rows() {
+        let err = Grid::parse("..\n.#", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "#"));
+
+        let err = Grid::parse("..\n...", Some).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert!(grid.column(1).eq(&['b', 'e']));
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn transposes_and_rotates_non_square_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn finds_neighbours_inside_the_grid() {
+        let grid = grid();
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            grid.neighbours(corner).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(corner).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.go(corner, Direction::North), None);
+    }
+}
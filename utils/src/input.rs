@@ -0,0 +1,114 @@
+//! Finding and reading puzzle inputs.
+//!
+//! Each day's input lives at `inputs/<year>/dayNN/input.txt` in the workspace. Another file
+//! can be given on the command line or in the `AOC_INPUT` environment variable, and a path
+//! of `-` reads from stdin instead.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Environment variable naming an input to use instead of the day's default.
+pub const ENV_VAR: &str = "AOC_INPUT";
+
+const INPUTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+/// The folder holding every input for a day.
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(INPUTS)
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    dir(year, day).join("input.txt")
+}
+
+/// Where to read an input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the input for a day: `arg` if given, then `AOC_INPUT`, then the day's
+    /// default file.
+    pub fn resolve(year: u16, day: u8, arg: Option<&str>) -> Self {
+        let arg = arg.map(String::from).or_else(|| env::var(ENV_VAR).ok());
+
+        match arg.as_deref() {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None => Source::File(default_path(year, day)),
+        }
+    }
+
+    /// Reads the whole input, with its line endings and whitespace normalised.
+    pub fn read(&self) -> io::Result<String> {
+        let text = match self {
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                text
+            }
+            Source::File(path) => fs::read_to_string(path)?,
+        };
+
+        Ok(normalise(&text))
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Converts CRLF line endings to LF and strips trailing whitespace from every line and
+/// trailing blank lines from the end, leaving a single final newline.
+///
+/// Leading whitespace is kept, since some inputs line their columns up with it.
+pub fn normalise(text: &str) -> String {
+    let mut normalised = String::with_capacity(text.len());
+
+    for line in text.lines() {
+        normalised.push_str(line.trim_end());
+        normalised.push('\n');
+    }
+
+    let end = normalised.trim_end().len();
+    normalised.truncate(end);
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    normalised
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_line_endings_and_trailing_whitespace() {
+        assert_eq!(normalise("a  \r\n\r\n  b\t\r\n\r\n\r\n"), "a\n\n  b\n");
+        assert_eq!(normalise("a\nb"), "a\nb\n");
+        assert_eq!(normalise("\n \n"), "");
+    }
+
+    #[test]
+    fn resolves_explicit_inputs() {
+        assert_eq!(Source::resolve(2023, 1, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::resolve(2023, 1, Some("x.txt")),
+            Source::File("x.txt".into())
+        );
+        assert!(default_path(2023, 1).ends_with("inputs/2023/day01/input.txt"));
+    }
+}
@@ -0,0 +1,72 @@
+//! Shared pieces used by every day's solution and by the `aoc` runner.
+
+pub mod alloc;
+mod answer;
+pub mod bench;
+pub mod cycle;
+pub mod error;
+pub mod expected;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod puzzles;
+pub mod render;
+pub mod run;
+pub mod scan;
+pub mod simulate;
+pub mod stream;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use run::run;
+
+/// A single day's puzzle. The input is parsed once and then shared by both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The puzzle input after parsing.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// A parsed input with the type of its [`Solution`] erased, so days with different `Input`
+/// types can sit in the same table.
+pub trait Parsed {
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+}
+
+struct Erased<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Erased<S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+}
+
+/// Parses `input` with `S`, hiding the parsed type behind [`Parsed`].
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError>
+where
+    S::Input: 'static,
+{
+    let parsed = parse_input::<S>(input)?;
+    Ok(Box::new(Erased::<S>(parsed)))
+}
+
+/// Parses `input` with `S`, naming the day in any error.
+pub fn parse_input<S: Solution>(input: &str) -> Result<S::Input, ParseError> {
+    S::parse(input).map_err(|err| err.in_puzzle(S::YEAR, S::DAY))
+}
@@ -0,0 +1,217 @@
+//! Number theory and counting that comes up again and again: cycles lining up, roots of
+//! quadratics, sequences that turn out to be polynomials, and areas of polygons drawn on a
+//! grid. Everything is done in integers, so nothing is lost to rounding.
+
+use crate::geometry::Vector;
+
+/// The greatest common divisor of `a` and `b`, or the other one if either is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, unless it is too big for a `u64`.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `gcd(a, b)` along with `x` and `y` such that `a * x + b * y` is that divisor.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (divisor, x, y) = extended_gcd(b, a % b);
+        (divisor, y, x - a / b * y)
+    }
+}
+
+/// The smallest `x` that leaves each remainder when divided by its modulus, along with
+/// the least common multiple of the moduli, the period after which `x` works again. The
+/// moduli need not be coprime, but then there may be no answer at all. `None` too if
+/// the answer is too big for an `i64`, or a modulus is not positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut period) = (0i128, 1i128);
+
+    for (remainder, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (remainder, modulus) = (remainder as i128, modulus as i128);
+        let (divisor, inverse, _) = extended_gcd(period, modulus);
+        if (remainder - x) % divisor != 0 {
+            return None;
+        }
+
+        // Step `x` on by whole periods until it also leaves `remainder`.
+        let steps = modulus / divisor;
+        let k = ((remainder - x) / divisor * inverse).rem_euclid(steps);
+        x += period * k;
+        period *= steps;
+        if period > i64::MAX as i128 {
+            return None;
+        }
+    }
+
+    Some((x as i64, period as i64))
+}
+
+/// The square root of `n`, rounded down.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method, starting above the root, comes down to it and then stops.
+    let bits = u64::BITS - n.leading_zeros();
+    let mut root = 1 << bits.div_ceil(2);
+    loop {
+        let next = (root + n / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// The square root of `n` if it is a whole number.
+pub fn exact_sqrt(n: u64) -> Option<u64> {
+    let root = isqrt(n);
+    (root * root == n).then_some(root)
+}
+
+/// The value at `x` of the simplest polynomial that gives `values` at 0, 1, 2 and so on,
+/// found by taking differences between neighbours until they are all zero. With as many
+/// values as the degree of the polynomial plus one, this is the Lagrange polynomial through
+/// them.
+///
+/// Panics if the answer does not fit in an `i64`.
+pub fn extrapolate(values: &[i64], x: i64) -> i64 {
+    let mut differences = values
+        .iter()
+        .map(|&value| value as i128)
+        .collect::<Vec<_>>();
+    let (x, mut choose, mut total) = (x as i128, 1i128, 0i128);
+
+    // Newton's forward differences: the sum of `x` choose `k` times the `k`th difference.
+    for k in 0..values.len() as i128 {
+        if differences.iter().all(|&difference| difference == 0) {
+            break;
+        }
+        total += choose * differences[0];
+        choose = choose * (x - k) / (k + 1);
+        differences = differences
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+    }
+
+    i64::try_from(total).expect("the extrapolated value should fit in an i64")
+}
+
+/// Twice the area of the polygon with `corners` in order, whichever way round. Doubling it
+/// keeps it whole when the corners are.
+pub fn twice_area(corners: &[Vector]) -> u64 {
+    let edges = corners.iter().zip(corners.iter().cycle().skip(1));
+    // The shoelace formula, which comes out negative when the corners go anticlockwise.
+    let sum: i64 = edges.map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+    sum.unsigned_abs()
+}
+
+/// How many whole points lie on the edges of the polygon with `corners` in order.
+pub fn boundary_points(corners: &[Vector]) -> u64 {
+    let edges = corners.iter().zip(corners.iter().cycle().skip(1));
+    edges
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+        .sum()
+}
+
+/// How many whole points lie strictly inside the polygon with whole `corners` in order,
+/// by Pick's theorem: the area is the points inside plus half those on the edges, less one.
+/// Polygons that fold back over themselves, like a line there and back, have none.
+pub fn interior_points(corners: &[Vector]) -> u64 {
+    twice_area(corners)
+        .saturating_add(2)
+        .saturating_sub(boundary_points(corners))
+        / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(u64::MAX, 3), Some(u64::MAX));
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing a factor, which agree on it and then which don't.
+        assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, i64::MAX), (1, i64::MAX - 1)]), None);
+        assert_eq!(crt([(2, 3), (0, 0)]), None);
+        assert_eq!(crt([(1, -4)]), None);
+    }
+
+    #[test]
+    fn takes_exact_square_roots() {
+        for n in (0..2000).chain([u64::MAX, u64::MAX - 1, (1 << 32) - 1, 1 << 62]) {
+            let root = isqrt(n);
+            assert!(root * root <= n, "{n}");
+            assert!((root + 1)
+                .checked_mul(root + 1)
+                .is_none_or(|above| above > n));
+        }
+        assert_eq!(exact_sqrt(144), Some(12));
+        assert_eq!(exact_sqrt(145), None);
+    }
+
+    #[test]
+    fn extrapolates_polynomials() {
+        // 3x^2 - x + 2
+        let values = [2, 4, 12, 26];
+        assert_eq!(extrapolate(&values, 4), 46);
+        assert_eq!(extrapolate(&values, -1), 6);
+        assert_eq!(extrapolate(&values[..3], 1000), 2_999_002);
+        assert_eq!(extrapolate(&[5, 5, 5], 9), 5);
+        assert_eq!(extrapolate(&[], 3), 0);
+    }
+
+    #[test]
+    fn measures_polygons_on_a_grid() {
+        // A 4 by 3 rectangle, then the same with a notch cut out of one corner.
+        let rectangle = [(0, 0), (4, 0), (4, 3), (0, 3)].map(|(x, y)| Vector::new(x, y));
+        assert_eq!(twice_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), 6);
+
+        let notched = [(0, 0), (2, 0), (2, 1), (4, 1), (4, 3), (0, 3)];
+        let notched = notched.map(|(x, y)| Vector::new(x, y));
+        assert_eq!(twice_area(&notched), 20);
+        assert_eq!(interior_points(&notched), 4);
+
+        let triangle = [(0, 0), (0, 4), (4, 0)].map(|(x, y)| Vector::new(x, y));
+        assert_eq!(twice_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+
+        let line = [(0, 0), (5, 0)].map(|(x, y)| Vector::new(x, y));
+        assert_eq!(twice_area(&line), 0);
+        assert_eq!(boundary_points(&line), 10);
+        assert_eq!(interior_points(&line), 0);
+    }
+}
@@ -0,0 +1,250 @@
+//! What is known about each puzzle beyond its solution: its title, how much of it is solved,
+//! what its input looks like and where a copy of its text is kept. Banners and messages
+//! naming a puzzle all come from here, so a day is only ever called one thing.
+
+use std::path::PathBuf;
+
+use crate::input;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// How many parts are solved, in order: 0 for a day just started, 1 for just the first,
+    /// 2 for both.
+    pub parts: u8,
+    /// What the input looks like, for people writing or checking one by hand.
+    pub input: &'static str,
+    /// A saved copy of the puzzle's text, relative to the day's input folder.
+    pub text: Option<&'static str>,
+}
+
+impl Puzzle {
+    /// The year, day and title, like `2023 Day 04: Scratchcards`, leaving out the title
+    /// until it is filled in.
+    pub fn name(&self) -> String {
+        match self.title {
+            "" => format!("{} Day {:02}", self.year, self.day),
+            title => format!("{} Day {:02}: {}", self.year, self.day, title),
+        }
+    }
+
+    /// Whether `part` has a solution.
+    pub fn solves(&self, part: usize) -> bool {
+        (1..=self.parts as usize).contains(&part)
+    }
+
+    /// Where the puzzle's text is saved, if it is.
+    pub fn text_path(&self) -> Option<PathBuf> {
+        self.text
+            .map(|text| input::dir(self.year, self.day).join(text))
+    }
+}
+
+/// A puzzle with both parts solved and no saved text.
+const fn puzzle(year: u16, day: u8, title: &'static str, input: &'static str) -> Puzzle {
+    Puzzle {
+        year,
+        day,
+        title,
+        parts: 2,
+        input,
+        text: None,
+    }
+}
+
+/// Every puzzle with a solution, in order.
+pub const PUZZLES: &[Puzzle] = &[
+    puzzle(
+        2016,
+        3,
+        "Squares With Three Sides",
+        "lines of three side lengths",
+    ),
+    puzzle(
+        2016,
+        4,
+        "Security Through Obscurity",
+        "rooms like `aaaaa-bbb-z-y-x-123[abxyz]`",
+    ),
+    puzzle(
+        2022,
+        1,
+        "Calorie Counting",
+        "lines of calories, in groups separated by blank lines",
+    ),
+    puzzle(2022, 2, "Rock Paper Scissors", "rounds like `A Y`"),
+    puzzle(2022, 3, "Rucksack Reorganization", "lines of letters"),
+    puzzle(2022, 4, "Camp Cleanup", "pairs of ranges like `2-4,6-8`"),
+    puzzle(
+        2022,
+        5,
+        "Supply Stacks",
+        "a drawing of stacks of crates, a blank line, then steps like `move 1 from 2 to 1`",
+    ),
+    puzzle(2022, 6, "Tuning Trouble", "one line of letters"),
+    puzzle(
+        2022,
+        7,
+        "No Space Left On Device",
+        "`cd` and `ls` commands and what they printed",
+    ),
+    puzzle(2023, 1, "Trebuchet?!", "lines of letters and digits"),
+    puzzle(
+        2023,
+        2,
+        "Cube Conundrum",
+        "games like `Game 1: 3 blue, 4 red; 1 red, 2 green`",
+    ),
+    puzzle(
+        2023,
+        4,
+        "Scratchcards",
+        "cards like `Card 1: 41 48 83 | 83 86 6 31`",
+    ),
+    puzzle(
+        2023,
+        5,
+        "If You Give A Seed A Fertilizer",
+        "a line of seeds, then maps of ranges like `seed-to-soil map:`",
+    ),
+    puzzle(
+        2023,
+        6,
+        "Wait For It",
+        "a line of race times and a line of record distances",
+    ),
+    puzzle(2023, 7, "Camel Cards", "hands and bids like `32T3K 765`"),
+    puzzle(
+        2023,
+        8,
+        "Haunted Wasteland",
+        "a line of `L` and `R`, a blank line, then nodes like `AAA = (BBB, CCC)`",
+    ),
+    puzzle(2023, 9, "Mirage Maintenance", "lines of numbers"),
+    puzzle(2023, 11, "Cosmic Expansion", "a grid of `.` and `#`"),
+    puzzle(
+        2023,
+        12,
+        "Hot Springs",
+        "rows of springs like `???.### 1,1,3`",
+    ),
+    puzzle(
+        2023,
+        13,
+        "Point of Incidence",
+        "grids of `.` and `#` separated by blank lines",
+    ),
+    puzzle(
+        2023,
+        14,
+        "Parabolic Reflector Dish",
+        "a grid of `.`, `#` and `O`",
+    ),
+    puzzle(
+        2023,
+        15,
+        "Lens Library",
+        "steps like `rn=1` or `cm-` separated by commas",
+    ),
+    puzzle(
+        2023,
+        16,
+        "The Floor Will Be Lava",
+        "a grid of `.`, `/`, `\\`, `|` and `-`",
+    ),
+    puzzle(2023, 17, "Clumsy Crucible", "a grid of digits"),
+    puzzle(
+        2023,
+        18,
+        "Lavaduct Lagoon",
+        "dig steps like `R 6 (#70c710)`",
+    ),
+    puzzle(
+        2023,
+        19,
+        "Aplenty",
+        "workflows like `px{a<2006:qkq,rfg}`, a blank line, then parts like `{x=787,m=2655}`",
+    ),
+    puzzle(2023, 20, "Pulse Propagation", "modules like `%a -> b, c`"),
+    puzzle(
+        2023,
+        21,
+        "Step Counter",
+        "a grid of `.` and `#` with one `S`",
+    ),
+    puzzle(2023, 22, "Sand Slabs", "bricks like `1,0,1~1,2,1`"),
+    puzzle(
+        2023,
+        23,
+        "A Long Walk",
+        "a grid of `.`, `#`, `^`, `<`, `>` and `v`",
+    ),
+];
+
+/// The puzzle for `day` of `year`, if it has a solution.
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// The name of the puzzle for `day` of `year`, or just the year and day if it is not known.
+pub fn name(year: u16, day: u8) -> String {
+    match find(year, day) {
+        Some(puzzle) => puzzle.name(),
+        None => format!("{} Day {:02}", year, day),
+    }
+}
+
+/// Whether `part` of `day` of `year` has a solution, assuming both parts do for puzzles
+/// that are not known.
+pub fn solves(year: u16, day: u8, part: usize) -> bool {
+    find(year, day).map_or(part == 1 || part == 2, |puzzle| puzzle.solves(part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_each_puzzle_once_in_order() {
+        let days = PUZZLES
+            .iter()
+            .map(|puzzle| (puzzle.year, puzzle.day))
+            .collect::<Vec<_>>();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(PUZZLES.iter().all(|puzzle| (1..=25).contains(&puzzle.day)
+            && puzzle.parts <= 2
+            && (puzzle.parts == 0 || !puzzle.title.is_empty() && !puzzle.input.is_empty())));
+    }
+
+    #[test]
+    fn names_puzzles() {
+        assert_eq!(name(2023, 4), "2023 Day 04: Scratchcards");
+        assert_eq!(name(2023, 11), "2023 Day 11: Cosmic Expansion");
+        assert_eq!(name(2015, 1), "2015 Day 01");
+
+        assert!(solves(2023, 4, 2));
+        assert!(solves(2015, 1, 1));
+        assert!(!solves(2023, 4, 3));
+        let first_only = Puzzle {
+            parts: 1,
+            text: Some("puzzle.md"),
+            ..puzzle(2023, 25, "Snowverload", "wires like `jqt: rhn xhk nvd`")
+        };
+        assert!(!first_only.solves(2));
+        assert_eq!(
+            first_only.text_path(),
+            Some(input::dir(2023, 25).join("puzzle.md"))
+        );
+
+        let started = Puzzle {
+            parts: 0,
+            ..puzzle(2024, 1, "", "")
+        };
+        assert_eq!(started.name(), "2024 Day 01");
+        assert!(!started.solves(1));
+    }
+}
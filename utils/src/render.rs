@@ -0,0 +1,286 @@
+//! Drawing grid days as pictures, one frame per step, to watch a simulation or see where it
+//! goes wrong.
+//!
+//! Frames are binary PPM files named `frame-00000.ppm`, `frame-00001.ppm` and so on, which
+//! most image viewers open and `ffmpeg -i frame-%05d.ppm out.mp4` turns into a video.
+
+use std::{
+    env, fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use crate::{
+    grid::{Grid, Point},
+    input::Source,
+    parse_input, Solution,
+};
+
+/// A red, green and blue colour.
+pub type Colour = [u8; 3];
+
+pub const BLACK: Colour = [0, 0, 0];
+pub const WHITE: Colour = [255, 255, 255];
+pub const GREY: Colour = [96, 96, 96];
+pub const RED: Colour = [220, 50, 47];
+pub const GREEN: Colour = [80, 200, 80];
+pub const BLUE: Colour = [38, 139, 210];
+pub const YELLOW: Colour = [250, 210, 60];
+
+/// Frames are drawn at least this many pixels across, so small examples are big enough to
+/// see.
+const MIN_SIZE: usize = 300;
+
+/// The colour `fraction` of the way from `from` to `to`.
+pub fn blend(from: Colour, to: Colour, fraction: f64) -> Colour {
+    let fraction = fraction.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| {
+        let (from, to) = (from[i] as f64, to[i] as f64);
+        (from + (to - from) * fraction).round() as u8
+    })
+}
+
+/// A colour for the `n`th of a set of things, such as letters, far enough from its
+/// neighbours' colours to tell them apart.
+pub fn distinct(n: usize) -> Colour {
+    // Stepping round the colour wheel by the golden angle never lands close to an earlier
+    // step.
+    let hue = (n as f64 * 137.508) % 360.0;
+    let sector = hue / 60.0;
+    let rising = sector.fract();
+    let (high, low) = (230, 70);
+    let mid = |t: f64| (low as f64 + (high - low) as f64 * t) as u8;
+
+    match sector as usize {
+        0 => [high, mid(rising), low],
+        1 => [mid(1.0 - rising), high, low],
+        2 => [low, high, mid(rising)],
+        3 => [low, mid(1.0 - rising), high],
+        4 => [mid(rising), low, high],
+        _ => [high, low, mid(1.0 - rising)],
+    }
+}
+
+/// A picture with one square per tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Colour>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Colour) -> Self {
+        Image {
+            pixels: Grid::new(width, height, background),
+        }
+    }
+
+    /// Draws every tile of `grid` in the colour `colour` picks for it.
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl FnMut(&T) -> Colour) -> Self {
+        Image {
+            pixels: grid.map(colour),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    /// Colours the tile at `point`, if it is inside the image.
+    pub fn set(&mut self, point: Point, colour: Colour) {
+        if let Some(pixel) = self.pixels.get_mut(point) {
+            *pixel = colour;
+        }
+    }
+
+    /// Colours every tile in `points`, leaving out any outside the image.
+    pub fn overlay(&mut self, points: impl IntoIterator<Item = Point>, colour: Colour) {
+        for point in points {
+            self.set(point, colour);
+        }
+    }
+
+    /// Writes the image as a binary PPM, drawing each tile as a `scale` by `scale` square.
+    pub fn write_ppm(&self, out: &mut impl Write, scale: usize) -> io::Result<()> {
+        let scale = scale.max(1);
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )?;
+
+        let mut line = Vec::with_capacity(self.width() * scale * 3);
+        for row in self.pixels.rows() {
+            line.clear();
+            for colour in row {
+                for _ in 0..scale {
+                    line.extend_from_slice(colour);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A folder of numbered frames.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl Frames {
+    /// Creates `dir` if needed and deletes any frames left in it by an earlier run, so they
+    /// don't end up in this run's animation.
+    pub fn create(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if name.starts_with("frame-") && name.ends_with(".ppm") {
+                fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(Frames { dir, count: 0 })
+    }
+
+    /// Writes `image` as the next frame.
+    pub fn push(&mut self, image: &Image) -> io::Result<()> {
+        let scale = MIN_SIZE.div_ceil(image.width().max(image.height()).max(1));
+        let path = self.dir.join(format!("frame-{:05}.ppm", self.count));
+
+        let mut out = BufWriter::new(fs::File::create(path)?);
+        image.write_ppm(&mut out, scale)?;
+        out.flush()?;
+
+        self.count += 1;
+        Ok(())
+    }
+
+    /// How many frames have been written.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// A day that can draw its work as it goes.
+pub trait Render: Solution {
+    /// Draws the steps taken to solve `input`, pushing one frame per step.
+    fn render(input: &Self::Input, frames: &mut Frames) -> io::Result<()>;
+}
+
+/// Like [`crate::run`], except that with `--render <dir>` as the first arguments, draws the
+/// day into `dir` instead of printing the answers. An input can follow the folder.
+pub fn run<S: Render>() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) != Some("--render") {
+        return crate::run::<S>();
+    }
+
+    let Some(dir) = args.get(1) else {
+        eprintln!("Usage: --render <dir> [input]");
+        return ExitCode::from(2);
+    };
+    let source = Source::resolve(S::YEAR, S::DAY, args.get(2).map(String::as_str));
+
+    match render_source::<S>(&source, Path::new(dir)) {
+        Ok(frames) => {
+            println!(
+                "Wrote {} frames to {}",
+                frames.count(),
+                frames.dir().display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            println!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads and parses the input from `source`, then draws it into `dir`.
+fn render_source<S: Render>(source: &Source, dir: &Path) -> Result<Frames, String> {
+    let input = source
+        .read()
+        .map_err(|err| format!("Could not read {}: {}", source, err))?;
+    let input =
+        parse_input::<S>(&input).map_err(|err| format!("Could not parse {}: {}", source, err))?;
+
+    let mut frames = Frames::create(dir)
+        .map_err(|err| format!("Could not create {}: {}", dir.display(), err))?;
+    S::render(&input, &mut frames)
+        .map_err(|err| format!("Could not write frames to {}: {}", dir.display(), err))?;
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let mut image = Image::new(2, 1, BLACK);
+        image.set(Point::new(1, 0), WHITE);
+        image.set(Point::new(5, 5), RED);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm, 2).unwrap();
+
+        let (header, pixels) = ppm.split_at(11);
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        let row = [BLACK, BLACK, WHITE, WHITE].concat();
+        assert_eq!(pixels, [row.clone(), row].concat());
+    }
+
+    #[test]
+    fn numbers_frames_and_clears_old_ones() {
+        let dir = env::temp_dir().join(format!("render-test-{}", std::process::id()));
+        let image = Image::from_grid(&Grid::parse("#.", Some).unwrap(), |&c| {
+            if c == '#' {
+                WHITE
+            } else {
+                BLACK
+            }
+        });
+
+        let mut frames = Frames::create(&dir).unwrap();
+        for _ in 0..3 {
+            frames.push(&image).unwrap();
+        }
+        let mut frames = Frames::create(&dir).unwrap();
+        frames.push(&image).unwrap();
+
+        let names = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["frame-00000.ppm"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn blends_and_picks_distinct_colours() {
+        assert_eq!(blend(BLACK, WHITE, 0.5), [128, 128, 128]);
+        assert_eq!(blend(BLACK, WHITE, 2.0), WHITE);
+        assert_ne!(distinct(0), distinct(1));
+    }
+}
@@ -0,0 +1,114 @@
+//! Output shared by each day's own binary and the `aoc` runner, so every day reports its
+//! answers the same way.
+
+use std::{env, process::ExitCode, time::Duration, time::Instant};
+
+use crate::{
+    alloc,
+    input::Source,
+    parse_input,
+    puzzles::{self, Puzzle},
+    Answer, Solution,
+};
+
+/// The line above a day's answers naming its puzzle, followed by where its text is saved
+/// if there is a copy.
+pub fn banner(year: u16, day: u8) -> String {
+    let line = format!("{:-<10} {} {:->10}", "", puzzles::name(year, day), "");
+    let text = puzzles::find(year, day)
+        .and_then(Puzzle::text_path)
+        .filter(|path| path.is_file());
+
+    match text {
+        Some(path) => format!("{}\nPuzzle text: {}", line, path.display()),
+        None => line,
+    }
+}
+
+pub fn part_line(part: usize, answer: &Answer, elapsed: Duration) -> String {
+    format!(
+        "Part {}: {:<15} | Elapsed Time: {:.2?}",
+        part, answer, elapsed
+    )
+}
+
+/// Reads back a line written by [`part_line`], giving the part, the answer and the elapsed
+/// time as they were printed.
+pub fn read_part_line(line: &str) -> Option<(usize, &str, &str)> {
+    let (part, rest) = line.strip_prefix("Part ")?.split_once(": ")?;
+    let (answer, elapsed) = rest.rsplit_once(" | Elapsed Time: ")?;
+    // Anything after the time, like what the part allocated, is left off.
+    let elapsed = elapsed.split(" | ").next().unwrap_or_default();
+    Some((part.parse().ok()?, answer.trim_end(), elapsed))
+}
+
+/// Solves both parts of `S` for the input named by the first command line argument, or
+/// the day's default input, printing the results.
+pub fn run<S: Solution>() -> ExitCode {
+    let arg = env::args().nth(1);
+    run_source::<S>(&Source::resolve(S::YEAR, S::DAY, arg.as_deref()))
+}
+
+/// Reads the input from `source`, then parses and solves both parts of `S`, printing the
+/// results. Fails if the input can't be read or parsed, or a part has no answer.
+pub fn run_source<S: Solution>(source: &Source) -> ExitCode {
+    println!("{}", banner(S::YEAR, S::DAY));
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(err) => {
+            println!("Could not read {}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match parse_input::<S>(&input) {
+        Ok(input) => input,
+        Err(err) => {
+            println!("Could not parse {}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut answered = true;
+    let solvers: [fn(&S::Input) -> Answer; 2] = [S::part1, S::part2];
+    for (part, solve) in solvers.into_iter().enumerate() {
+        if !puzzles::solves(S::YEAR, S::DAY, part + 1) {
+            continue;
+        }
+        let before = Instant::now();
+        let (answer, memory) = alloc::measure(|| solve(&input));
+        answered &= answer.failure().is_none();
+        let line = part_line(part + 1, &answer, before.elapsed());
+        match memory {
+            Some(memory) => println!("{line} | {memory}"),
+            None => println!("{line}"),
+        }
+    }
+
+    if answered {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_back_part_lines() {
+        let line = part_line(2, &Answer::Text("CMZ".into()), Duration::from_micros(1500));
+        assert_eq!(read_part_line(&line), Some((2, "CMZ", "1.50ms")));
+        let counted = format!("{line} | 3 allocs, 1.5 KiB allocated, 1.0 KiB peak");
+        assert_eq!(read_part_line(&counted), Some((2, "CMZ", "1.50ms")));
+        assert_eq!(read_part_line(&banner(2023, 1)), None);
+
+        let failed = part_line(1, &Answer::failed("no path"), Duration::from_micros(1500));
+        assert_eq!(
+            read_part_line(&failed),
+            Some((1, "no answer, no path", "1.50ms"))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! A cursor over the bytes of an input for pulling out numbers and words without
+//! allocating, for the days where splitting every line into `&str`s is the slow part.
+
+use std::{any, error::Error, fmt};
+
+use crate::error::ParseError;
+
+/// An integer that can be built up one decimal digit at a time.
+pub trait Integer: Copy {
+    const ZERO: Self;
+    /// Whether a leading `-` should be read as part of the number.
+    const SIGNED: bool;
+
+    /// `self * 10 + digit`, or `None` on overflow.
+    fn push_digit(self, digit: u8) -> Option<Self>;
+
+    /// `self * 10 - digit`, or `None` on overflow. Negative numbers are built downwards so
+    /// that the minimum value can be read.
+    fn push_neg_digit(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const SIGNED: bool = $signed;
+
+                fn push_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_add(digit as Self)
+                }
+
+                fn push_neg_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_sub(digit as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+impl_integer!(true: i8, i16, i32, i64, i128, isize);
+
+/// A number in the input that does not fit in the type it was read as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// Byte offset of the start of the number.
+    pub offset: usize,
+    /// Length of the number in bytes, including any sign.
+    pub len: usize,
+    pub ty: &'static str,
+}
+
+impl Overflow {
+    /// Points at the number in `input`, given the slice of it that was `scanned`.
+    pub fn in_input(&self, input: &str, scanned: &str) -> ParseError {
+        let number = &scanned[self.offset..self.offset + self.len];
+        ParseError::at(input, number, format!("a number that fits in {}", self.ty))
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Number at byte {} does not fit in {}",
+            self.offset, self.ty
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Scanner {
+            bytes: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    /// Byte offset of the next unread byte.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Skips up to and including the next `delim`. Returns `false`, leaving the scanner
+    /// empty, if there is none.
+    pub fn skip_past(&mut self, delim: u8) -> bool {
+        match self.bytes[self.pos..].iter().position(|&b| b == delim) {
+            Some(i) => {
+                self.pos += i + 1;
+                true
+            }
+            None => {
+                self.pos = self.bytes.len();
+                false
+            }
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(|b| b.is_ascii_whitespace());
+    }
+
+    /// Skips to the next digit and returns its value.
+    pub fn next_digit(&mut self) -> Option<u8> {
+        self.skip_while(|b| !b.is_ascii_digit());
+        let digit = self.next()? - b'0';
+        Some(digit)
+    }
+
+    /// Skips to the next number and reads it, stopping just after its last digit.
+    ///
+    /// For signed types a `-` directly before the digits makes the number negative, so
+    /// `1-3` reads as `1` then `-3`; unsigned types treat the `-` as a separator.
+    pub fn next_number<T: Integer>(&mut self) -> Result<Option<T>, Overflow> {
+        let negative = loop {
+            match self.peek() {
+                None => return Ok(None),
+                Some(b'0'..=b'9') => break false,
+                Some(b'-')
+                    if T::SIGNED
+                        && self.bytes.get(self.pos + 1).is_some_and(u8::is_ascii_digit) =>
+                {
+                    self.pos += 1;
+                    break true;
+                }
+                Some(_) => self.pos += 1,
+            }
+        };
+
+        let start = self.pos - negative as usize;
+
+        let mut value = T::ZERO;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            let digit = digit - b'0';
+            let next = if negative {
+                value.push_neg_digit(digit)
+            } else {
+                value.push_digit(digit)
+            };
+            value = match next {
+                Some(next) => next,
+                None => {
+                    self.skip_while(|b| b.is_ascii_digit());
+                    return Err(Overflow {
+                        offset: start,
+                        len: self.pos - start,
+                        ty: any::type_name::<T>(),
+                    });
+                }
+            };
+            self.pos += 1;
+        }
+
+        Ok(Some(value))
+    }
+
+    /// Skips to the next identifier, a letter or `_` followed by any letters, digits or
+    /// `_`s, and returns it.
+    pub fn next_ident(&mut self) -> Option<&'a str> {
+        self.skip_while(|b| !(b.is_ascii_alphabetic() || b == b'_'));
+        if self.is_empty() {
+            return None;
+        }
+
+        let start = self.pos;
+        self.skip_while(|b| b.is_ascii_alphanumeric() || b == b'_');
+
+        // Only ASCII bytes were taken, so the slice is still valid UTF-8.
+        std::str::from_utf8(&self.bytes[start..self.pos]).ok()
+    }
+
+    fn skip_while(&mut self, pred: impl Fn(u8) -> bool) {
+        while self.peek().is_some_and(&pred) {
+            self.pos += 1;
+        }
+    }
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_numbers_between_separators() {
+        let mut scan = Scanner::new("Game 12: 3 blue, 40 red");
+        assert_eq!(scan.next_number::<u32>(), Ok(Some(12)));
+        assert_eq!(scan.next_number::<u32>(), Ok(Some(3)));
+        assert_eq!(scan.next_ident(), Some("blue"));
+        assert_eq!(scan.next_number::<u32>(), Ok(Some(40)));
+        assert_eq!(scan.next_ident(), Some("red"));
+        assert_eq!(scan.next_number::<u32>(), Ok(None));
+        assert_eq!(scan.next_ident(), None);
+    }
+
+    #[test]
+    fn reads_negative_numbers_only_when_signed() {
+        let mut scan = Scanner::new("-5 2-3 -");
+        assert_eq!(scan.next_number::<i32>(), Ok(Some(-5)));
+        assert_eq!(scan.next_number::<i32>(), Ok(Some(2)));
+        assert_eq!(scan.next_number::<i32>(), Ok(Some(-3)));
+        assert_eq!(scan.next_number::<i32>(), Ok(None));
+
+        let mut scan = Scanner::new("2-3");
+        assert_eq!(scan.next_number::<u32>(), Ok(Some(2)));
+        assert_eq!(scan.next_number::<u32>(), Ok(Some(3)));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(Scanner::new("-128").next_number::<i8>(), Ok(Some(-128)));
+        assert_eq!(Scanner::new("255").next_number::<u8>(), Ok(Some(255)));
+
+        let err = Scanner::new("x 2560 y").next_number::<u8>().unwrap_err();
+        assert_eq!((err.offset, err.len), (2, 4));
+        assert!(Scanner::new("-129").next_number::<i8>().is_err());
+    }
+
+    #[test]
+    fn skips_past_delimiters() {
+        let mut scan = Scanner::new("Card 1: 41 48");
+        assert!(scan.skip_past(b':'));
+        assert_eq!(scan.next_number::<u8>(), Ok(Some(41)));
+        assert!(!scan.skip_past(b':'));
+        assert!(scan.is_empty());
+    }
+}
@@ -0,0 +1,249 @@
+//! Stepping through a simulation by hand in the terminal, to watch what it does instead of
+//! adding `println!`s. Run a day with `--debug`, then type commands at the prompt:
+//!
+//! ```text
+//! cargo run -p advent-2023-q14 -- --debug [input]
+//! ```
+
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use crate::{input::Source, parse_input, Solution};
+
+/// A day that works by running a simulation a step at a time.
+pub trait Simulation: Solution {
+    /// Everything that changes as the simulation runs.
+    type State;
+
+    /// The state before the first step.
+    fn start(input: &Self::Input) -> Self::State;
+
+    /// Takes one step.
+    fn step(input: &Self::Input, state: &mut Self::State);
+
+    /// Draws `state` as text.
+    fn render(input: &Self::Input, state: &Self::State) -> String;
+
+    /// Whether there are no steps left to take.
+    fn done(input: &Self::Input, state: &Self::State) -> bool;
+}
+
+const HELP: &str = "\
+Commands:
+  [n | next] [count]  take one step, or `count` steps
+  g | goto <step>     run forwards or back to `step`
+  r | run             run until the simulation is done
+  p | print           show the state again
+  h | help            show this list
+  q | quit            stop debugging";
+
+/// A simulation of `S` and how many steps it has taken.
+struct Debugger<'a, S: Simulation> {
+    input: &'a S::Input,
+    state: S::State,
+    taken: usize,
+}
+
+impl<'a, S: Simulation> Debugger<'a, S> {
+    fn new(input: &'a S::Input) -> Self {
+        Debugger {
+            input,
+            state: S::start(input),
+            taken: 0,
+        }
+    }
+
+    /// Takes up to `count` steps, stopping early if the simulation is done.
+    fn advance(&mut self, count: usize) {
+        for _ in 0..count {
+            if S::done(self.input, &self.state) {
+                break;
+            }
+            S::step(self.input, &mut self.state);
+            self.taken += 1;
+        }
+    }
+
+    /// Gets to step `n`, starting again from the beginning if it is behind.
+    fn goto(&mut self, n: usize) {
+        if n < self.taken {
+            *self = Debugger::new(self.input);
+        }
+        self.advance(n - self.taken);
+    }
+
+    fn show(&self, out: &mut impl Write) -> io::Result<()> {
+        let done = if S::done(self.input, &self.state) {
+            " (done)"
+        } else {
+            ""
+        };
+        writeln!(out, "Step {}{}", self.taken, done)?;
+        writeln!(out, "{}", S::render(self.input, &self.state))
+    }
+}
+
+/// Steps through `S` on `input`, following `commands` one line at a time and writing what
+/// happens to `out`, until the commands run out or say to quit.
+pub fn debug<S: Simulation>(
+    input: &S::Input,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> io::Result<()> {
+    let mut debugger = Debugger::<S>::new(input);
+    debugger.show(&mut out)?;
+
+    let mut commands = commands.lines();
+    loop {
+        write!(out, "{}> ", debugger.taken)?;
+        out.flush()?;
+        let Some(line) = commands.next() else {
+            return writeln!(out);
+        };
+        let line = line?;
+
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let count = match words.get(1).map(|word| word.parse::<usize>()) {
+            None => None,
+            Some(Ok(count)) => Some(count),
+            Some(Err(_)) => {
+                writeln!(out, "Expected a number of steps, found {:?}", words[1])?;
+                continue;
+            }
+        };
+
+        match (words.first().copied(), count) {
+            (None, _) => debugger.advance(1),
+            (Some("n" | "next"), count) => debugger.advance(count.unwrap_or(1)),
+            (Some("g" | "goto"), Some(step)) => debugger.goto(step),
+            (Some("r" | "run"), None) => debugger.advance(usize::MAX),
+            (Some("p" | "print"), None) => {}
+            (Some("h" | "help"), _) => {
+                writeln!(out, "{}", HELP)?;
+                continue;
+            }
+            (Some("q" | "quit"), _) => return Ok(()),
+            _ => {
+                writeln!(out, "Unknown command {:?}, try `help`", line.trim())?;
+                continue;
+            }
+        }
+        debugger.show(&mut out)?;
+    }
+}
+
+/// Like [`crate::run`], except that with `--debug` as the first argument, steps through the
+/// simulation in the terminal instead of printing the answers. An input can follow.
+pub fn run<S: Simulation>() -> ExitCode {
+    run_with::<S>(crate::run::<S>)
+}
+
+/// Like [`run`], except that without `--debug` it runs `otherwise`, for days that take
+/// other flags too, such as [`crate::render::run`].
+pub fn run_with<S: Simulation>(otherwise: fn() -> ExitCode) -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) != Some("--debug") {
+        return otherwise();
+    }
+
+    let source = Source::resolve(S::YEAR, S::DAY, args.get(1).map(String::as_str));
+    match debug_source::<S>(&source) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            println!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads and parses the input from `source`, then steps through it from the terminal.
+fn debug_source<S: Simulation>(source: &Source) -> Result<(), String> {
+    if let Source::Stdin = source {
+        return Err("Cannot debug an input from stdin, as the commands come from there".into());
+    }
+    let input = source
+        .read()
+        .map_err(|err| format!("Could not read {}: {}", source, err))?;
+    let input =
+        parse_input::<S>(&input).map_err(|err| format!("Could not parse {}: {}", source, err))?;
+
+    println!("Debugging {}, type `help` for commands", source);
+    debug::<S>(&input, io::stdin().lock(), io::stdout().lock())
+        .map_err(|err| format!("Could not debug {}: {}", source, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError};
+
+    /// Counts down from the number in the input.
+    struct Countdown;
+
+    impl Solution for Countdown {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        type Input = u32;
+
+        fn parse(input: &str) -> Result<u32, ParseError> {
+            crate::error::parse_at(input, input, "a number")
+        }
+
+        fn part1(input: &u32) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(input: &u32) -> Answer {
+            (*input).into()
+        }
+    }
+
+    impl Simulation for Countdown {
+        type State = u32;
+
+        fn start(input: &u32) -> u32 {
+            *input
+        }
+
+        fn step(_: &u32, state: &mut u32) {
+            *state -= 1;
+        }
+
+        fn render(_: &u32, state: &u32) -> String {
+            format!("at {}", state)
+        }
+
+        fn done(_: &u32, state: &u32) -> bool {
+            *state == 0
+        }
+    }
+
+    fn session(commands: &str) -> String {
+        let mut out = Vec::new();
+        debug::<Countdown>(&5, commands.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn steps_and_jumps() {
+        let out = session("\nn 2\ngoto 1\nrun\np\nq\nn\n");
+        let shown = out
+            .lines()
+            .filter(|line| line.starts_with("at "))
+            .collect::<Vec<_>>();
+        assert_eq!(shown, ["at 5", "at 4", "at 2", "at 4", "at 0", "at 0"]);
+        assert!(out.contains("Step 5 (done)"));
+    }
+
+    #[test]
+    fn explains_bad_commands() {
+        let out = session("n two\nfly\n");
+        assert!(out.contains("Expected a number of steps, found \"two\""));
+        assert!(out.contains("Unknown command \"fly\", try `help`"));
+        assert!(out.ends_with("0> \n"));
+    }
+}
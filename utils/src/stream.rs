@@ -0,0 +1,241 @@
+//! Solving line by line straight from a reader, for days whose answers only need a little
+//! kept from each line. The input is never held in memory as a whole, so stdin pipes and
+//! generated inputs bigger than memory work as well as the usual files.
+
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    process::ExitCode,
+    time::Instant,
+};
+
+use crate::{
+    expected,
+    input::Source,
+    puzzles,
+    run::{banner, part_line},
+    Answer, ParseError, Solution,
+};
+
+/// A day that can be answered one line at a time.
+pub trait Stream: Solution {
+    /// What is kept of the lines read so far, enough to answer both parts.
+    type State: Default;
+
+    /// Reads the next line into `state`. Errors point at `line` as if it were the whole
+    /// input, and are moved to the right line by [`read`].
+    fn read_line(state: &mut Self::State, line: &str) -> Result<(), ParseError>;
+
+    /// Checks `state` once every line is read, for problems only the whole input shows.
+    /// Errors point at `last`, the last line that was not blank, as if it were the whole
+    /// input.
+    fn finish(_state: &Self::State, _last: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn answer1(state: &Self::State) -> Answer;
+
+    fn answer2(state: &Self::State) -> Answer;
+}
+
+/// Parses each line of `input` with `parse`, pointing any error at its place in `input`.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+/// Reads every line from `reader` into a new [`Stream::State`], one at a time.
+///
+/// Lines are cleaned up the way [`crate::input::normalise`] does it, so streaming sees the
+/// same input as reading it whole: trailing whitespace is dropped, and so are blank lines
+/// at the end.
+pub fn read<S: Stream>(mut reader: impl BufRead) -> Result<S::State, Box<dyn Error>> {
+    let mut state = S::State::default();
+    let mut buffer = String::new();
+    let mut number = 0;
+    let mut blank = 0;
+    let mut last = String::new();
+
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            let last_number = (number - blank).max(1);
+            S::finish(&state, &last)
+                .map_err(|err| err.on_line(last_number).in_puzzle(S::YEAR, S::DAY))?;
+            return Ok(state);
+        }
+        number += 1;
+
+        let line = buffer.trim_end();
+        if line.is_empty() {
+            blank += 1;
+            continue;
+        }
+
+        // Blank lines only count once there is something after them.
+        for skipped in number - blank..number {
+            S::read_line(&mut state, "")
+                .map_err(|err| err.on_line(skipped).in_puzzle(S::YEAR, S::DAY))?;
+        }
+        blank = 0;
+
+        S::read_line(&mut state, line)
+            .map_err(|err| err.on_line(number).in_puzzle(S::YEAR, S::DAY))?;
+        last.clear();
+        last.push_str(line);
+    }
+}
+
+/// Streams the input from `source`, then answers both parts of `S`, printing the results.
+pub fn run_source<S: Stream>(source: &Source) -> ExitCode {
+    println!("{}", banner(S::YEAR, S::DAY));
+
+    let reader: Box<dyn BufRead> = match source {
+        Source::Stdin => Box::new(io::stdin().lock()),
+        Source::File(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                println!("Could not read {}: {}", source, err);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let before = Instant::now();
+    let state = match read::<S>(reader) {
+        Ok(state) => state,
+        Err(err) => {
+            println!("Could not stream {}: {}", source, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("Streamed in {:.2?}", before.elapsed());
+
+    let mut answered = true;
+    let answers: [fn(&S::State) -> Answer; 2] = [S::answer1, S::answer2];
+    for (part, answer) in answers.into_iter().enumerate() {
+        if !puzzles::solves(S::YEAR, S::DAY, part + 1) {
+            continue;
+        }
+        let before = Instant::now();
+        let answer = answer(&state);
+        answered &= answer.failure().is_none();
+        println!("{}", part_line(part + 1, &answer, before.elapsed()));
+    }
+
+    if answered {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Like [`crate::run`], except that with `--stream` as the first argument, reads the input
+/// a line at a time instead of all at once. An input can follow, with `-` for stdin.
+pub fn run<S: Stream>() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) != Some("--stream") {
+        return crate::run::<S>();
+    }
+
+    run_source::<S>(&Source::resolve(
+        S::YEAR,
+        S::DAY,
+        args.get(1).map(String::as_str),
+    ))
+}
+
+/// Streams every example and input listed in the day's `answers.toml`, panicking with
+/// every answer that does not match. Meant to be called from each day's tests, next to
+/// [`expected::check`].
+pub fn check<S: Stream>() {
+    expected::check_with(
+        S::YEAR,
+        S::DAY,
+        |text| read::<S>(text.as_bytes()),
+        [S::answer1, S::answer2],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums the numbers in each blank line separated group, keeping the largest.
+    struct Groups;
+
+    impl Solution for Groups {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 1;
+
+        type Input = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Answer {
+            unreachable!()
+        }
+
+        fn part2(_: &()) -> Answer {
+            unreachable!()
+        }
+    }
+
+    impl Stream for Groups {
+        /// The largest group, the current group and how many groups there are.
+        type State = (u32, u32, u32);
+
+        fn read_line(state: &mut Self::State, line: &str) -> Result<(), ParseError> {
+            if line.is_empty() {
+                state.1 = 0;
+                state.2 += 1;
+            } else {
+                state.1 += crate::error::parse_at::<u32>(line, line, "a number")?;
+                state.0 = state.0.max(state.1);
+            }
+            Ok(())
+        }
+
+        fn answer1(state: &Self::State) -> Answer {
+            state.0.into()
+        }
+
+        fn answer2(state: &Self::State) -> Answer {
+            (state.2 + 1).into()
+        }
+    }
+
+    #[test]
+    fn streams_lines_like_the_whole_input() {
+        let state = read::<Groups>("1\r\n2  \n\n\n5\n\n \n".as_bytes()).unwrap();
+        assert_eq!(state, (5, 5, 2));
+
+        let err = read::<Groups>("1\n\n2\nx3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2022 Day 01: Calorie Counting, line 4, column 1: expected a number, found \"x3\"; \
+             the input should be lines of calories, in groups separated by blank lines"
+        );
+    }
+
+    #[test]
+    fn points_line_errors_into_the_input() {
+        let input = "1 2\n3 x\n";
+        let err = parse_lines(input, |line| {
+            line.split(' ')
+                .map(|n| crate::error::parse_at::<u32>(line, n, "a number"))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}